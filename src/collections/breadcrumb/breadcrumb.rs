@@ -0,0 +1,159 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use crate::collections::Icon;
+use super::{BreadcrumbDivider, BreadcrumbSection};
+
+/// A breadcrumb is used to show hierarchy between content.
+pub struct Breadcrumb {
+    props: BreadcrumbProps,
+    classes: Vec<String>,
+}
+
+/// Shorthand for a [`BreadcrumbSection`], see [`BreadcrumbProps::sections`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BreadcrumbSectionItem {
+    /// Emitted by `on_section_click` when the section is clicked.
+    pub key: String,
+    /// Content of the section.
+    pub content: String,
+    /// Renders the section as a link to this location.
+    pub href: Option<String>,
+    /// Style as the currently active section.
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct BreadcrumbProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Shorthand for primary content of the BreadcrumbDivider.
+    #[prop_or_else(|| None)]
+    pub divider: Option<String>,
+    /// Render the BreadcrumbDivider as an Icon by pass an <Icon />.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<Icon>,
+    /// Called with the key of a shorthand section when it is clicked.
+    #[prop_or_default]
+    pub on_section_click: Callback<String>,
+    /// Shorthand array of sections, rendered with dividers between them.
+    #[prop_or_default]
+    pub sections: Vec<BreadcrumbSectionItem>,
+    /// Size of Breadcrumb.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+}
+
+impl Component for Breadcrumb {
+    type Message = ();
+    type Properties = BreadcrumbProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                >
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        let sections = self.props.sections.iter().enumerate().map(|(index, section)| {
+            let on_section_click = self.props.on_section_click.clone();
+            let key = section.key.clone();
+            html! {
+                <>
+                  {
+                      if index > 0 {
+                          self.render_divider()
+                      } else {
+                          html! {}
+                      }
+                  }
+                  <BreadcrumbSection
+                    active=section.active
+                    content=section.content.clone()
+                    href=section.href.clone()
+                    link=section.href.is_none() && !section.active
+                    on_click=Callback::from(move |_| on_section_click.emit(key.clone()))
+                  />
+                </>
+            }
+        });
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              { for sections }
+              {
+                  match self.props.content {
+                      Some(ref content) => html! { content.clone() },
+                      None => html! {}
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl Breadcrumb {
+    fn render_divider(&self) -> Html {
+        match self.props.divider {
+            Some(ref divider) => html! {
+                <BreadcrumbDivider content=divider.clone() icon=self.props.icon.clone() />
+            },
+            None => html! {
+                <BreadcrumbDivider icon=self.props.icon.clone() />
+            },
+        }
+    }
+}
+
+impl BreadcrumbProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            size,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(size),
+            use_str("breadcrumb"),
+            use_option(class_name)
+        )
+    }
+}
@@ -0,0 +1,89 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use crate::collections::Icon;
+
+/// A divider sub-component for Breadcrumb component.
+pub struct BreadcrumbDivider {
+    props: BreadcrumbDividerProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct BreadcrumbDividerProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| "/".to_string())]
+    pub content: String,
+    /// Render as an Icon component by pass an <Icon />.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<Icon>,
+}
+
+impl Component for BreadcrumbDivider {
+    type Message = ();
+    type Properties = BreadcrumbDividerProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if !self.props.icon.is_empty() {
+            return html! {
+                { for self.props.icon.iter().map(|mut icon| {
+                    let class_name = cx!(use_str("divider"), use_option(&icon.props.class_name));
+                    icon.props.class_name = Some(class_name.join(" "));
+                    icon
+                }) }
+            }
+        }
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! { self.props.content.clone() }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl BreadcrumbDividerProps {
+    fn derive_classes(&self) -> Vec<String> {
+        cx!(
+            use_str("divider"),
+            use_option(&self.class_name)
+        )
+    }
+}
@@ -0,0 +1,109 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+
+/// A section sub-component for Breadcrumb component.
+pub struct BreadcrumbSection {
+    link: ComponentLink<Self>,
+    props: BreadcrumbSectionProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct BreadcrumbSectionProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Style as the currently active section.
+    #[prop_or(false)]
+    pub active: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Render as an `a` tag instead of a `div` and adds the href attribute.
+    #[prop_or_else(|| None)]
+    pub href: Option<String>,
+    /// Render as an `a` tag instead of a `div`.
+    #[prop_or(false)]
+    pub link: bool,
+    /// Called on click.
+    #[prop_or_default]
+    pub on_click: Callback<MouseEvent>,
+}
+
+impl Component for BreadcrumbSection {
+    type Message = MouseEvent;
+    type Properties = BreadcrumbSectionProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { link, props, classes }
+    }
+
+    fn update(&mut self, event: Self::Message) -> ShouldRender {
+        self.props.on_click.emit(event);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.get_element_type() }
+              class=classes!(self.classes.as_slice())
+              href=self.props.href.clone()
+              onclick=self.link.callback(|e| e)
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl BreadcrumbSectionProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            active,
+            class_name,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*active, "active"),
+            use_str("section"),
+            use_option(class_name)
+        )
+    }
+
+    fn get_element_type(&self) -> String {
+        if self.root == "div" && (self.link || self.href.is_some()) {
+            "a".to_string()
+        } else {
+            self.root.clone()
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod breadcrumb;
+mod breadcrumb_divider;
+mod breadcrumb_section;
+
+pub use breadcrumb::*;
+pub use breadcrumb_divider::*;
+pub use breadcrumb_section::*;
\ No newline at end of file
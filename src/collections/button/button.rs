@@ -6,7 +6,7 @@ use crate::helper::*;
 use crate::collections::{Icon, Label};
 
 /// A Button indicates a possible user action.
-/// Also see [`Form`], [`Icon`](crate::collections::Icon), [`Label`]
+/// Also see [`Form`](crate::collections::Form), [`Icon`](crate::collections::Icon), [`Label`]
 pub struct Button {
     link: ComponentLink<Self>,
     props: ButtonProps,
@@ -323,7 +323,7 @@ impl ButtonProps {
             label,
             ..
         } = self;
-        if !attached.unset() || !label.is_empty() {
+        if *attached != Either::Left(false) || !label.is_empty() {
             return "div".to_string()
         }
         return self.root.clone()
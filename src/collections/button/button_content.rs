@@ -0,0 +1,93 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+
+/// Used in some Button types, such as `animated`.
+pub struct ButtonContent {
+    props: ButtonContentProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ButtonContentProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Initially hidden, visible on hover.
+    #[prop_or(false)]
+    pub hidden: bool,
+    /// Initially visible, hidden on hover.
+    #[prop_or(false)]
+    pub visible: bool,
+}
+
+impl Component for ButtonContent {
+    type Message = ();
+    type Properties = ButtonContentProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl ButtonContentProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            hidden,
+            visible,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*hidden, "hidden"),
+            use_key(*visible, "visible"),
+            use_str("content"),
+            use_option(class_name)
+        )
+    }
+}
@@ -0,0 +1,178 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::{Button, ButtonAttachedPosition, ButtonProps};
+
+/// Buttons can be grouped.
+pub struct ButtonGroup {
+    props: ButtonGroupProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ButtonGroupProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Groups can be attached to other content.
+    #[prop_or_else(|| Either::Left(false))]
+    pub attached: Either<bool, ButtonAttachedPosition>,
+    /// Groups can be less pronounced.
+    #[prop_or(false)]
+    pub basic: bool,
+    /// Array of shorthand Button values.
+    #[prop_or_default]
+    pub buttons: Vec<ButtonProps>,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Groups can have a shared color.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// Groups can reduce their padding to fit into tighter spaces.
+    #[prop_or(false)]
+    pub compact: bool,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Groups can be aligned to the left or right of its container.
+    #[prop_or_else(|| None)]
+    pub floated: Option<sui::Float>,
+    /// Groups can take the width of their container.
+    #[prop_or(false)]
+    pub fluid: bool,
+    /// Groups can be formatted as icons.
+    #[prop_or(false)]
+    pub icon: bool,
+    /// Groups can be formatted to appear on dark backgrounds.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// Groups can be formatted as labeled icon buttons.
+    #[prop_or(false)]
+    pub labeled: bool,
+    /// Groups can have different sizes.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// Groups can be formatted to appear vertically.
+    #[prop_or(false)]
+    pub vertical: bool,
+    /// Groups can have their widths divided evenly.
+    #[prop_or_else(|| None)]
+    pub widths: Option<sui::Widths>,
+}
+
+impl Component for ButtonGroup {
+    type Message = ();
+    type Properties = ButtonGroupProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                >
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        if !self.props.buttons.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                >
+                  { for self.props.buttons.iter().map(|button| self.render_button(button)) }
+                </@>
+            }
+        }
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  match self.props.content {
+                      Some(ref content) => html! { content.clone() },
+                      None => html! {}
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl ButtonGroup {
+    fn render_button(&self, button: &ButtonProps) -> Html {
+        let mut props = button.clone();
+        if props.color.is_none() {
+            props.color = self.props.color;
+        }
+        if props.size.is_none() {
+            props.size = self.props.size;
+        }
+        html! { <Button with props /> }
+    }
+}
+
+impl ButtonGroupProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            attached,
+            basic,
+            class_name,
+            color,
+            compact,
+            floated,
+            fluid,
+            icon,
+            inverted,
+            labeled,
+            size,
+            vertical,
+            widths,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(color),
+            use_option(size),
+            use_key(*basic, "basic"),
+            use_key(*compact, "compact"),
+            use_key(*fluid, "fluid"),
+            use_key(*icon, "icon"),
+            use_key(*inverted, "inverted"),
+            use_key(*labeled, "labeled"),
+            use_key(*vertical, "vertical"),
+            use_key_or_option_and_key(attached, "attached"),
+            use_option_and_key(floated, "floated"),
+            use_option(widths),
+            use_str("buttons"),
+            use_option(class_name)
+        )
+    }
+}
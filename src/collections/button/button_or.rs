@@ -0,0 +1,65 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+
+/// Button groups can contain conditionals.
+pub struct ButtonOr {
+    props: ButtonOrProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ButtonOrProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Or buttons can have their text localized, or adjusted by using the text prop.
+    #[prop_or_else(|| None)]
+    pub text: Option<String>,
+}
+
+impl Component for ButtonOr {
+    type Message = ();
+    type Properties = ButtonOrProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+              data-text=self.props.text.clone()
+            />
+        }
+    }
+}
+
+impl ButtonOrProps {
+    fn derive_classes(&self) -> Vec<String> {
+        cx!(
+            use_str("or"),
+            use_option(&self.class_name)
+        )
+    }
+}
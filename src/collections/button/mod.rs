@@ -1,3 +1,9 @@
 mod button;
+mod button_content;
+mod button_group;
+mod button_or;
 
-pub use button::*;
\ No newline at end of file
+pub use button::*;
+pub use button_content::*;
+pub use button_group::*;
+pub use button_or::*;
\ No newline at end of file
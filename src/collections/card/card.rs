@@ -0,0 +1,186 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use crate::collections::Image;
+use crate::collections::image::ImageProps;
+use super::CardContent;
+
+/// A card displays site content in a manner similar to a playing card.
+/// Also see [`CardGroup`](crate::collections::CardGroup), [`CardContent`](crate::collections::CardContent)
+pub struct Card {
+    link: ComponentLink<Self>,
+    props: CardProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct CardProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// A Card can center itself inside its container.
+    #[prop_or(false)]
+    pub centered: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A Card can be formatted to display different colors.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Shorthand for CardDescription.
+    #[prop_or_else(|| None)]
+    pub description: Option<String>,
+    /// Shorthand for primary content of CardContent with `extra`.
+    #[prop_or_else(|| None)]
+    pub extra: Option<Html>,
+    /// A Card can be formatted to take up the width of its container.
+    #[prop_or(false)]
+    pub fluid: bool,
+    /// Shorthand for CardHeader.
+    #[prop_or_else(|| None)]
+    pub header: Option<String>,
+    /// Render as an `a` tag instead of a `div` and adds the href attribute.
+    #[prop_or_else(|| None)]
+    pub href: Option<String>,
+    /// A card can contain an Image component.
+    #[prop_or_else(|| None)]
+    pub image: Option<ImageProps>,
+    /// A card can be formatted to link to other content.
+    #[prop_or(false)]
+    pub link: bool,
+    /// Shorthand for CardMeta.
+    #[prop_or_else(|| None)]
+    pub meta: Option<String>,
+    /// Called on click.
+    #[prop_or_else(|| None)]
+    pub on_click: Option<Callback<MouseEvent>>,
+    /// A Card can be formatted to raise above the page.
+    #[prop_or(false)]
+    pub raised: bool,
+}
+
+impl Component for Card {
+    type Message = MouseEvent;
+    type Properties = CardProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { link, props, classes }
+    }
+
+    fn update(&mut self, event: Self::Message) -> ShouldRender {
+        if let Some(cb) = self.props.on_click.as_ref() {
+            cb.emit(event);
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.get_element_type() }
+              class=classes!(self.classes.as_slice())
+              href=self.props.href.clone()
+              onclick=self.link.callback(|e| e)
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else if let Some(ref content) = self.props.content {
+                      html! { content.clone() }
+                  } else {
+                      self.render_shorthand()
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl Card {
+    fn render_shorthand(&self) -> Html {
+        let image = match self.props.image {
+            Some(ref image) => {
+                let mut props = image.clone();
+                props.ui = false;
+                props.wrapped = true;
+                html! { <Image with props /> }
+            }
+            None => html! {}
+        };
+        let content = if self.props.header.is_some()
+            || self.props.meta.is_some()
+            || self.props.description.is_some() {
+            html! {
+                <CardContent
+                  header=self.props.header.clone()
+                  meta=self.props.meta.clone()
+                  description=self.props.description.clone()
+                />
+            }
+        } else {
+            html! {}
+        };
+        let extra = match self.props.extra {
+            Some(ref extra) => html! { <CardContent extra=true>{ extra.clone() }</CardContent> },
+            None => html! {}
+        };
+
+        html! {
+            <>
+              { image }
+              { content }
+              { extra }
+            </>
+        }
+    }
+}
+
+impl CardProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            centered,
+            class_name,
+            color,
+            fluid,
+            link,
+            raised,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(color),
+            use_key(*centered, "centered"),
+            use_key(*fluid, "fluid"),
+            use_key(*link, "link"),
+            use_key(*raised, "raised"),
+            use_str("card"),
+            use_option(class_name)
+        )
+    }
+
+    fn get_element_type(&self) -> String {
+        if self.root == "div" && (self.href.is_some() || self.on_click.is_some()) {
+            "a".to_string()
+        } else {
+            self.root.clone()
+        }
+    }
+}
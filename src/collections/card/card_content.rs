@@ -0,0 +1,123 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::{CardDescription, CardHeader, CardMeta};
+
+/// A card can contain blocks of content or extra content meant to be formatted separately from the main content.
+pub struct CardContent {
+    props: CardContentProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct CardContentProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Shorthand for CardDescription.
+    #[prop_or_else(|| None)]
+    pub description: Option<String>,
+    /// A card can contain extra content meant to be formatted separately from the main content.
+    #[prop_or(false)]
+    pub extra: bool,
+    /// Shorthand for CardHeader.
+    #[prop_or_else(|| None)]
+    pub header: Option<String>,
+    /// Shorthand for CardMeta.
+    #[prop_or_else(|| None)]
+    pub meta: Option<String>,
+    /// A card content can adjust its text alignment.
+    #[prop_or_else(|| None)]
+    pub text_align: Option<sui::TextAlign>,
+}
+
+impl Component for CardContent {
+    type Message = ();
+    type Properties = CardContentProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else if let Some(ref content) = self.props.content {
+                      html! { content.clone() }
+                  } else {
+                      html! {
+                          <>
+                            {
+                                match self.props.header {
+                                    Some(ref header) => html! { <CardHeader content=header.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                            {
+                                match self.props.meta {
+                                    Some(ref meta) => html! { <CardMeta content=meta.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                            {
+                                match self.props.description {
+                                    Some(ref description) => html! { <CardDescription content=description.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                          </>
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl CardContentProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            extra,
+            text_align,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*extra, "extra"),
+            use_option_text_align(text_align),
+            use_str("content"),
+            use_option(class_name)
+        )
+    }
+}
@@ -0,0 +1,117 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::{Card, CardProps};
+
+/// A group of cards.
+pub struct CardGroup {
+    props: CardGroupProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct CardGroupProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// A group of cards can center itself inside its container.
+    #[prop_or(false)]
+    pub centered: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A group of cards can double its column width for mobile.
+    #[prop_or(false)]
+    pub doubling: bool,
+    /// Shorthand array of props for Card.
+    #[prop_or_default]
+    pub items: Vec<CardProps>,
+    /// A group of cards can set how many cards should exist in a row.
+    #[prop_or_else(|| None)]
+    pub items_per_row: Option<sui::Widths>,
+    /// A group of cards can automatically stack rows to a single columns on mobile devices.
+    #[prop_or(false)]
+    pub stackable: bool,
+    /// A card group can adjust its text alignment.
+    #[prop_or_else(|| None)]
+    pub text_align: Option<sui::TextAlign>,
+}
+
+impl Component for CardGroup {
+    type Message = ();
+    type Properties = CardGroupProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else if let Some(ref content) = self.props.content {
+                      html! { content.clone() }
+                  } else {
+                      html! {
+                          for self.props.items.iter().map(|item| {
+                              let props = item.clone();
+                              html! { <Card with props /> }
+                          })
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl CardGroupProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            centered,
+            class_name,
+            doubling,
+            items_per_row,
+            stackable,
+            text_align,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_key(*centered, "centered"),
+            use_key(*doubling, "doubling"),
+            use_key(*stackable, "stackable"),
+            use_option_text_align(text_align),
+            use_option(items_per_row),
+            use_str("cards"),
+            use_option(class_name)
+        )
+    }
+}
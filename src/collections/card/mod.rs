@@ -0,0 +1,13 @@
+mod card;
+mod card_content;
+mod card_description;
+mod card_group;
+mod card_header;
+mod card_meta;
+
+pub use card::*;
+pub use card_content::*;
+pub use card_description::*;
+pub use card_group::*;
+pub use card_header::*;
+pub use card_meta::*;
\ No newline at end of file
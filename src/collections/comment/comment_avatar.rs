@@ -0,0 +1,66 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+
+/// A comment can contain an image or avatar.
+pub struct CommentAvatar {
+    props: CommentAvatarProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct CommentAvatarProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Specifies the URL of the image.
+    #[prop_or_else(|| None)]
+    pub src: Option<String>,
+}
+
+impl Component for CommentAvatar {
+    type Message = ();
+    type Properties = CommentAvatarProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              <img src=self.props.src.clone() />
+            </@>
+        }
+    }
+}
+
+impl CommentAvatarProps {
+    fn derive_classes(&self) -> Vec<String> {
+        cx!(
+            use_str("avatar"),
+            use_option(&self.class_name)
+        )
+    }
+}
@@ -0,0 +1,148 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::{Comment, CommentAuthor, CommentAvatar, CommentContent, CommentMetadata, CommentText};
+
+/// Comments can be grouped, a comment can nest a group to display its replies.
+/// Also see [`Comment`](crate::collections::Comment)
+pub struct CommentGroup {
+    props: CommentGroupProps,
+    classes: Vec<String>,
+}
+
+/// Shorthand for a [`Comment`] and its replies, see [`CommentGroupProps::comments`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommentItem {
+    /// Name of the author of the comment.
+    pub author: String,
+    /// URL of the avatar of the author.
+    pub avatar: Option<String>,
+    /// When the comment was written, rendered as it is.
+    pub timestamp: String,
+    /// Text of the comment.
+    pub text: String,
+    /// Replies to the comment, rendered in a nested group.
+    pub children: Vec<CommentItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct CommentGroupProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Comments can be collapsed, or hidden from view.
+    #[prop_or(false)]
+    pub collapsed: bool,
+    /// Shorthand tree of comments, replies are rendered in nested groups.
+    #[prop_or_default]
+    pub comments: Vec<CommentItem>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Comments can hide extra information unless a user shows intent to interact with a comment.
+    #[prop_or(false)]
+    pub minimal: bool,
+    /// Comments can have different sizes.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// A comment list can be threaded to showing the relationship between conversations.
+    #[prop_or(false)]
+    pub threaded: bool,
+}
+
+impl Component for CommentGroup {
+    type Message = ();
+    type Properties = CommentGroupProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else if let Some(ref content) = self.props.content {
+                      html! { content.clone() }
+                  } else {
+                      html! { for self.props.comments.iter().map(render_comment) }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+fn render_comment(item: &CommentItem) -> Html {
+    html! {
+        <Comment>
+          {
+              match item.avatar {
+                  Some(ref avatar) => html! { <CommentAvatar src=avatar.clone() /> },
+                  None => html! {}
+              }
+          }
+          <CommentContent>
+            <CommentAuthor content=item.author.clone() />
+            <CommentMetadata content=item.timestamp.clone() />
+            <CommentText content=item.text.clone() />
+          </CommentContent>
+          {
+              if !item.children.is_empty() {
+                  html! { <CommentGroup comments=item.children.clone() /> }
+              } else {
+                  html! {}
+              }
+          }
+        </Comment>
+    }
+}
+
+impl CommentGroupProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            collapsed,
+            minimal,
+            size,
+            threaded,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(size),
+            use_key(*collapsed, "collapsed"),
+            use_key(*minimal, "minimal"),
+            use_key(*threaded, "threaded"),
+            use_str("comments"),
+            use_option(class_name)
+        )
+    }
+}
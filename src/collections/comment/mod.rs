@@ -0,0 +1,17 @@
+mod comment;
+mod comment_actions;
+mod comment_author;
+mod comment_avatar;
+mod comment_content;
+mod comment_group;
+mod comment_metadata;
+mod comment_text;
+
+pub use comment::*;
+pub use comment_actions::*;
+pub use comment_author::*;
+pub use comment_avatar::*;
+pub use comment_content::*;
+pub use comment_group::*;
+pub use comment_metadata::*;
+pub use comment_text::*;
\ No newline at end of file
@@ -0,0 +1,442 @@
+use std::collections::BTreeSet;
+
+use either::Either;
+use yew::prelude::*;
+
+use crate::sui;
+use crate::collections::{Icon, Menu, MenuItem, Table, TableBody, TableCell, TableFooter, TableHeader, TableHeaderCell, TableRow};
+use crate::collections::menu::MenuFloated;
+use crate::collections::table::{TableCompact, TableSorted};
+use super::DataTableColumn;
+
+/// A data table renders rows of data through column definitions, with sorting,
+/// row selection and client-side pagination.
+/// Also see [`DataTableColumn`](crate::collections::DataTableColumn), [`Table`](crate::collections::Table)
+pub struct DataTable<T: Clone + PartialEq + 'static> {
+    link: ComponentLink<Self>,
+    props: DataTableProps<T>,
+    sort: Option<(usize, TableSorted)>,
+    selected: BTreeSet<usize>,
+    page: usize,
+    page_size: usize,
+}
+
+pub enum DataTableMsg {
+    Sort(usize),
+    ToggleRow(usize),
+    TogglePage,
+    SetPage(usize),
+    SetPageSize(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct DataTableProps<T: Clone + PartialEq + 'static> {
+    /// A table may be divided each row into separate cells.
+    #[prop_or(false)]
+    pub celled: bool,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A table can be given a color to distinguish it from other tables.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// Column definitions of the table.
+    pub columns: Vec<DataTableColumn<T>>,
+    /// A table may sometimes need to be more compact to make more rows visible at a time.
+    #[prop_or_else(|| Either::Left(false))]
+    pub compact: Either<bool, TableCompact>,
+    /// Rows of the table.
+    pub data: Vec<T>,
+    /// Initial number of rows per page.
+    #[prop_or(10)]
+    pub default_page_size: usize,
+    /// A table's colors can be inverted.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// Called with the selected rows whenever the selection changes.
+    #[prop_or_default]
+    pub on_selection_change: Callback<Vec<T>>,
+    /// Page sizes offered by the page size selector.
+    #[prop_or_else(|| vec![10, 25, 50])]
+    pub page_sizes: Vec<usize>,
+    /// Adds a checkbox column to select rows.
+    #[prop_or(false)]
+    pub selection: bool,
+    /// A table can also be small or large.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// A table can stripe alternate rows of content with a darker color to increase contrast.
+    #[prop_or(false)]
+    pub striped: bool,
+}
+
+impl<T: Clone + PartialEq + 'static> Component for DataTable<T> {
+    type Message = DataTableMsg;
+    type Properties = DataTableProps<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let page_size = props.default_page_size.max(1);
+        Self {
+            link,
+            props,
+            sort: None,
+            selected: BTreeSet::new(),
+            page: 0,
+            page_size,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            DataTableMsg::Sort(column) => {
+                if !matches!(self.props.columns.get(column), Some(c) if c.is_sortable()) {
+                    return false;
+                }
+                self.sort = match self.sort {
+                    Some((current, TableSorted::Ascending)) if current == column => Some((column, TableSorted::Descending)),
+                    _ => Some((column, TableSorted::Ascending)),
+                };
+                self.page = 0;
+            }
+            DataTableMsg::ToggleRow(index) => {
+                if !self.selected.remove(&index) {
+                    self.selected.insert(index);
+                }
+                self.emit_selection();
+            }
+            DataTableMsg::TogglePage => {
+                let rows = self.page_rows();
+                if self.is_page_selected(&rows) {
+                    for index in rows {
+                        self.selected.remove(&index);
+                    }
+                } else {
+                    self.selected.extend(rows);
+                }
+                self.emit_selection();
+            }
+            DataTableMsg::SetPage(page) => {
+                if page == self.page || page >= self.page_count() {
+                    return false;
+                }
+                self.page = page;
+            }
+            DataTableMsg::SetPageSize(page_size) => {
+                if page_size == 0 || page_size == self.page_size {
+                    return false;
+                }
+                self.page_size = page_size;
+                self.page = 0;
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            if props.data != self.props.data {
+                if !self.selected.is_empty() {
+                    self.selected.clear();
+                    props.on_selection_change.emit(vec![]);
+                }
+                self.page = 0;
+            }
+            let same_columns = props.columns
+                .iter()
+                .map(DataTableColumn::header)
+                .eq(self.props.columns.iter().map(DataTableColumn::header));
+            if !same_columns {
+                self.sort = None;
+            }
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let rows = self.page_rows();
+
+        html! {
+            <Table
+              class_name=self.props.class_name.clone()
+              celled=self.props.celled
+              color=self.props.color
+              compact=self.props.compact
+              inverted=self.props.inverted
+              size=self.props.size
+              sortable=self.props.columns.iter().any(DataTableColumn::is_sortable)
+              striped=self.props.striped
+            >
+              <TableHeader>
+                <TableRow>
+                  { self.render_select_all(&rows) }
+                  { for self.props.columns.iter().enumerate().map(|(index, column)| self.render_header_cell(index, column)) }
+                </TableRow>
+              </TableHeader>
+              <TableBody>
+                { for rows.iter().map(|index| self.render_row(*index)) }
+              </TableBody>
+              <TableFooter>
+                <TableRow>
+                  <TableHeaderCell col_span={ self.props.columns.len() + self.props.selection as usize }>
+                    { self.render_page_size_selector() }
+                    { self.render_pagination() }
+                  </TableHeaderCell>
+                </TableRow>
+              </TableFooter>
+            </Table>
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> DataTable<T> {
+    /// Indices into `data` of the rows on the current page.
+    fn page_rows(&self) -> Vec<usize> {
+        let rows = sorted_rows(&self.props.data, &self.props.columns, self.sort);
+        page_rows(rows, self.page, self.page_size)
+    }
+
+    fn page_count(&self) -> usize {
+        match self.props.data.len() {
+            0 => 1,
+            len => (len - 1) / self.page_size + 1,
+        }
+    }
+
+    fn is_page_selected(&self, rows: &[usize]) -> bool {
+        !rows.is_empty() && rows.iter().all(|index| self.selected.contains(index))
+    }
+
+    fn emit_selection(&self) {
+        let rows = self.selected
+            .iter()
+            .filter_map(|index| self.props.data.get(*index).cloned())
+            .collect();
+        self.props.on_selection_change.emit(rows);
+    }
+
+    fn render_checkbox(&self, checked: bool, onclick: Callback<MouseEvent>) -> Html {
+        html! {
+            <div class="ui fitted checkbox">
+              <input type="checkbox" checked=checked onclick=onclick />
+              <label />
+            </div>
+        }
+    }
+
+    fn render_select_all(&self, rows: &[usize]) -> Html {
+        if !self.props.selection {
+            return html! {}
+        }
+
+        html! {
+            <TableHeaderCell collapsing=true>
+              { self.render_checkbox(self.is_page_selected(rows), self.link.callback(|_| DataTableMsg::TogglePage)) }
+            </TableHeaderCell>
+        }
+    }
+
+    fn render_header_cell(&self, index: usize, column: &DataTableColumn<T>) -> Html {
+        if !column.is_sortable() {
+            return html! { <TableHeaderCell content=column.header().to_string() disabled=true /> }
+        }
+
+        let sorted = match self.sort {
+            Some((current, direction)) if current == index => Some(direction),
+            _ => None,
+        };
+        // A sorted header already shows the caret of the sortable table, only
+        // unsorted ones get an Icon hinting that they can be sorted.
+        let icon = match sorted {
+            Some(_) => html! {},
+            None => html! { <Icon name="sort" disabled=true /> },
+        };
+
+        html! {
+            <TableHeaderCell
+              sorted=sorted
+              on_click=self.link.callback(move |_| DataTableMsg::Sort(index))
+            >
+              { column.header() }
+              { icon }
+            </TableHeaderCell>
+        }
+    }
+
+    fn render_row(&self, index: usize) -> Html {
+        let row = &self.props.data[index];
+        let selected = self.selected.contains(&index);
+
+        html! {
+            <TableRow active=selected>
+              {
+                  if self.props.selection {
+                      html! {
+                          <TableCell collapsing=true>
+                            { self.render_checkbox(selected, self.link.callback(move |_| DataTableMsg::ToggleRow(index))) }
+                          </TableCell>
+                      }
+                  } else {
+                      html! {}
+                  }
+              }
+              { for self.props.columns.iter().map(|column| html! { <TableCell>{ column.render_cell(row) }</TableCell> }) }
+            </TableRow>
+        }
+    }
+
+    fn render_page_size_selector(&self) -> Html {
+        if self.props.page_sizes.is_empty() {
+            return html! {}
+        }
+
+        let onchange = self.link.batch_callback(|data| match data {
+            ChangeData::Select(select) => select.value().parse().ok().map(DataTableMsg::SetPageSize),
+            _ => None,
+        });
+
+        html! {
+            <select class="ui compact selection dropdown" onchange=onchange>
+              {
+                  for self.props.page_sizes.iter().map(|size| html! {
+                      <option value=size.to_string() selected={ *size == self.page_size }>{ size }</option>
+                  })
+              }
+            </select>
+        }
+    }
+
+    fn render_pagination(&self) -> Html {
+        let page_count = self.page_count();
+        let page = self.page;
+
+        html! {
+            <Menu floated=Either::Right(MenuFloated::Right) pagination=true>
+              <MenuItem
+                disabled={ page == 0 }
+                on_click=self.link.callback(move |_| DataTableMsg::SetPage(page.saturating_sub(1)))
+              >
+                <Icon name="chevron left" />
+              </MenuItem>
+              {
+                  for page_items(page, page_count).into_iter().map(|item| match item {
+                      Some(index) => html! {
+                          <MenuItem
+                            active={ index == page }
+                            on_click=self.link.callback(move |_| DataTableMsg::SetPage(index))
+                          >
+                            { index + 1 }
+                          </MenuItem>
+                      },
+                      None => html! { <MenuItem disabled=true>{ "..." }</MenuItem> },
+                  })
+              }
+              <MenuItem
+                disabled={ page + 1 >= page_count }
+                on_click=self.link.callback(move |_| DataTableMsg::SetPage(page + 1))
+              >
+                <Icon name="chevron right" />
+              </MenuItem>
+            </Menu>
+        }
+    }
+}
+
+/// Indices into `data` of all rows in display order.
+fn sorted_rows<T: 'static>(data: &[T], columns: &[DataTableColumn<T>], sort: Option<(usize, TableSorted)>) -> Vec<usize> {
+    let mut rows: Vec<usize> = (0..data.len()).collect();
+    if let Some((column, direction)) = sort {
+        if let Some(column) = columns.get(column) {
+            match direction {
+                TableSorted::Ascending => rows.sort_by(|a, b| column.compare(&data[*a], &data[*b])),
+                TableSorted::Descending => rows.sort_by(|a, b| column.compare(&data[*b], &data[*a])),
+            }
+        }
+    }
+    rows
+}
+
+/// The rows of `rows` on the given page.
+fn page_rows(rows: Vec<usize>, page: usize, page_size: usize) -> Vec<usize> {
+    rows.into_iter()
+        .skip(page * page_size)
+        .take(page_size)
+        .collect()
+}
+
+/// Pages shown by the pagination menu, `None` standing for an ellipsis.
+/// An ellipsis always hides at least two pages, a single page is shown instead.
+fn page_items(page: usize, page_count: usize) -> Vec<Option<usize>> {
+    if page_count <= 7 {
+        return (0..page_count).map(Some).collect();
+    }
+
+    let start = page.saturating_sub(1).max(1);
+    let end = (page + 1).min(page_count - 2);
+    let mut items = vec![Some(0)];
+    if start > 2 {
+        items.push(None);
+    } else if start == 2 {
+        items.push(Some(1));
+    }
+    items.extend((start..=end).map(Some));
+    if end + 3 < page_count {
+        items.push(None);
+    } else if end + 3 == page_count {
+        items.push(Some(page_count - 2));
+    }
+    items.push(Some(page_count - 1));
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number_column() -> DataTableColumn<u32> {
+        DataTableColumn::new("Number", |n: &u32| html! { n }).sort_by_key(|n: &u32| *n)
+    }
+
+    #[test]
+    fn page_items_shows_all_pages_when_few() {
+        assert_eq!(page_items(0, 1), vec![Some(0)]);
+        assert_eq!(page_items(3, 7), (0..7).map(Some).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn page_items_collapses_gaps_of_two_pages_or_more() {
+        assert_eq!(page_items(0, 10), vec![Some(0), Some(1), None, Some(9)]);
+        assert_eq!(page_items(5, 10), vec![Some(0), None, Some(4), Some(5), Some(6), None, Some(9)]);
+        assert_eq!(page_items(9, 10), vec![Some(0), None, Some(8), Some(9)]);
+    }
+
+    #[test]
+    fn page_items_shows_a_single_hidden_page() {
+        assert_eq!(page_items(3, 8), vec![Some(0), Some(1), Some(2), Some(3), Some(4), None, Some(7)]);
+        assert_eq!(page_items(4, 8), vec![Some(0), None, Some(3), Some(4), Some(5), Some(6), Some(7)]);
+    }
+
+    #[test]
+    fn sorting_applies_across_pages() {
+        let data = vec![3, 7, 1, 9, 5];
+        let columns = vec![number_column()];
+        let values = |rows: Vec<usize>| rows.into_iter().map(|i| data[i]).collect::<Vec<_>>();
+
+        let ascending = sorted_rows(&data, &columns, Some((0, TableSorted::Ascending)));
+        assert_eq!(values(page_rows(ascending.clone(), 0, 2)), vec![1, 3]);
+        assert_eq!(values(page_rows(ascending.clone(), 1, 2)), vec![5, 7]);
+        assert_eq!(values(page_rows(ascending, 2, 2)), vec![9]);
+
+        let descending = sorted_rows(&data, &columns, Some((0, TableSorted::Descending)));
+        assert_eq!(values(page_rows(descending.clone(), 0, 2)), vec![9, 7]);
+        assert_eq!(values(page_rows(descending, 1, 2)), vec![5, 3]);
+    }
+
+    #[test]
+    fn unsorted_rows_keep_data_order() {
+        let data = vec![3, 7, 1];
+        assert_eq!(sorted_rows(&data, &[number_column()], None), vec![0, 1, 2]);
+    }
+}
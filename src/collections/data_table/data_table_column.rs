@@ -0,0 +1,91 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+type CellFn<T> = dyn Fn(&T) -> Html;
+type CompareFn<T> = dyn Fn(&T, &T) -> Ordering;
+
+/// A column of a [`DataTable`](crate::collections::DataTable).
+///
+/// A column has a header, renders the cell of each row through a closure and
+/// becomes sortable once it is given a comparator by [`sort_by`](Self::sort_by)
+/// or a key by [`sort_by_key`](Self::sort_by_key).
+pub struct DataTableColumn<T> {
+    header: String,
+    cell: Rc<CellFn<T>>,
+    compare: Option<Rc<CompareFn<T>>>,
+}
+
+impl<T: 'static> DataTableColumn<T> {
+    pub fn new<H: Into<String>, F: Fn(&T) -> Html + 'static>(header: H, cell: F) -> Self {
+        Self {
+            header: header.into(),
+            cell: Rc::new(cell),
+            compare: None,
+        }
+    }
+
+    /// Makes the column sortable by comparing rows with `compare`.
+    pub fn sort_by<F: Fn(&T, &T) -> Ordering + 'static>(mut self, compare: F) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Makes the column sortable by comparing the keys extracted from rows by `key`.
+    pub fn sort_by_key<K: Ord, F: Fn(&T) -> K + 'static>(self, key: F) -> Self {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    pub fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    pub fn render_cell(&self, row: &T) -> Html {
+        (self.cell)(row)
+    }
+
+    pub fn compare(&self, a: &T, b: &T) -> Ordering {
+        match self.compare {
+            Some(ref compare) => compare(a, b),
+            None => Ordering::Equal,
+        }
+    }
+}
+
+impl<T> Clone for DataTableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            cell: self.cell.clone(),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for DataTableColumn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let same_compare = match (&self.compare, &other.compare) {
+            (Some(a), Some(b)) => Rc::as_ptr(a) as *const u8 == Rc::as_ptr(b) as *const u8,
+            (None, None) => true,
+            _ => false,
+        };
+        self.header == other.header
+            && Rc::as_ptr(&self.cell) as *const u8 == Rc::as_ptr(&other.cell) as *const u8
+            && same_compare
+    }
+}
+
+impl<T> fmt::Debug for DataTableColumn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataTableColumn")
+            .field("header", &self.header)
+            .field("sortable", &self.compare.is_some())
+            .finish()
+    }
+}
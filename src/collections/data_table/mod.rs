@@ -0,0 +1,5 @@
+mod data_table;
+mod data_table_column;
+
+pub use data_table::*;
+pub use data_table_column::*;
\ No newline at end of file
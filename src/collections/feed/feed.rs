@@ -0,0 +1,148 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use crate::collections::Icon;
+use super::{FeedContent, FeedEvent, FeedExtra, FeedLabel, FeedMeta, FeedSummary};
+
+/// A feed presents user activity chronologically.
+/// Also see [`FeedEvent`](crate::collections::FeedEvent)
+pub struct Feed {
+    props: FeedProps,
+    classes: Vec<String>,
+}
+
+/// Shorthand for a [`FeedEvent`], see [`FeedProps::events`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FeedEventItem {
+    /// Name of an icon rendered as the label of the event.
+    pub icon: Option<String>,
+    /// URL of an image rendered as the label of the event.
+    pub image: Option<String>,
+    /// User who triggered the event, rendered before the summary.
+    pub user: Option<String>,
+    /// Summary of the event.
+    pub summary: String,
+    /// When the event happened, rendered as it is.
+    pub date: Option<String>,
+    /// Additional text of the event.
+    pub extra_text: Option<String>,
+    /// URLs of additional images of the event.
+    pub extra_images: Vec<String>,
+    /// Label of the like of the event, e.g. a like count, rendered in its meta.
+    pub like: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FeedProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Shorthand array of events.
+    #[prop_or_default]
+    pub events: Vec<FeedEventItem>,
+    /// A feed can have different sizes.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+}
+
+impl Component for Feed {
+    type Message = ();
+    type Properties = FeedProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else if let Some(ref content) = self.props.content {
+                      html! { content.clone() }
+                  } else {
+                      html! { for self.props.events.iter().map(render_event) }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+fn render_event(item: &FeedEventItem) -> Html {
+    let label = match (&item.icon, &item.image) {
+        (Some(icon), _) => html! { <FeedLabel><Icon name=icon.clone() /></FeedLabel> },
+        (None, Some(image)) => html! { <FeedLabel image=image.clone() /> },
+        (None, None) => html! {},
+    };
+    let extra_text = match item.extra_text {
+        Some(ref text) => html! { <FeedExtra text=true content=text.clone() /> },
+        None => html! {}
+    };
+    let extra_images = if !item.extra_images.is_empty() {
+        html! { <FeedExtra images=item.extra_images.clone() /> }
+    } else {
+        html! {}
+    };
+    let meta = match item.like {
+        Some(ref like) => html! { <FeedMeta like=like.clone() /> },
+        None => html! {}
+    };
+
+    html! {
+        <FeedEvent>
+          { label }
+          <FeedContent>
+            <FeedSummary user=item.user.clone() content=item.summary.clone() date=item.date.clone() />
+            { extra_text }
+            { extra_images }
+            { meta }
+          </FeedContent>
+        </FeedEvent>
+    }
+}
+
+impl FeedProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            size,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(class_name),
+            use_option(size),
+            use_str("feed")
+        )
+    }
+}
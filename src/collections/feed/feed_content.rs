@@ -0,0 +1,131 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use super::{FeedDate, FeedExtra, FeedMeta, FeedSummary};
+
+/// An event can contain content.
+pub struct FeedContent {
+    props: FeedContentProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FeedContentProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// An event can contain a date.
+    #[prop_or_else(|| None)]
+    pub date: Option<String>,
+    /// Shorthand for FeedExtra with images.
+    #[prop_or_default]
+    pub extra_images: Vec<String>,
+    /// Shorthand for FeedExtra with text.
+    #[prop_or_else(|| None)]
+    pub extra_text: Option<String>,
+    /// Shorthand for FeedMeta.
+    #[prop_or_else(|| None)]
+    pub meta: Option<String>,
+    /// Shorthand for FeedSummary.
+    #[prop_or_else(|| None)]
+    pub summary: Option<String>,
+}
+
+impl Component for FeedContent {
+    type Message = ();
+    type Properties = FeedContentProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          <>
+                            {
+                                match self.props.date {
+                                    Some(ref date) => html! { <FeedDate content=date.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                            {
+                                match self.props.summary {
+                                    Some(ref summary) => html! { <FeedSummary content=summary.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                            {
+                                match self.props.content {
+                                    Some(ref content) => html! { content.clone() },
+                                    None => html! {}
+                                }
+                            }
+                            {
+                                match self.props.extra_text {
+                                    Some(ref text) => html! { <FeedExtra text=true content=text.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                            {
+                                if !self.props.extra_images.is_empty() {
+                                    html! { <FeedExtra images=self.props.extra_images.clone() /> }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                            {
+                                match self.props.meta {
+                                    Some(ref meta) => html! { <FeedMeta content=meta.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                          </>
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl FeedContentProps {
+    fn derive_classes(&self) -> Vec<String> {
+        cx!(
+            use_str("content"),
+            use_option(&self.class_name)
+        )
+    }
+}
@@ -0,0 +1,133 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use crate::collections::Icon;
+use super::{FeedContent, FeedLabel};
+
+/// A feed contains an event.
+pub struct FeedEvent {
+    props: FeedEventProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FeedEventProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Shorthand for FeedDate.
+    #[prop_or_else(|| None)]
+    pub date: Option<String>,
+    /// Shorthand for FeedExtra with images.
+    #[prop_or_default]
+    pub extra_images: Vec<String>,
+    /// Shorthand for FeedExtra with text.
+    #[prop_or_else(|| None)]
+    pub extra_text: Option<String>,
+    /// An event can contain an icon label, by pass an <Icon />.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<Icon>,
+    /// An event can contain an image label, by pass its URL.
+    #[prop_or_else(|| None)]
+    pub image: Option<String>,
+    /// Shorthand for FeedMeta.
+    #[prop_or_else(|| None)]
+    pub meta: Option<String>,
+    /// Shorthand for FeedSummary.
+    #[prop_or_else(|| None)]
+    pub summary: Option<String>,
+}
+
+impl Component for FeedEvent {
+    type Message = ();
+    type Properties = FeedEventProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          <>
+                            {
+                                if !self.props.icon.is_empty() || self.props.image.is_some() {
+                                    html! { <FeedLabel icon=self.props.icon.clone() image=self.props.image.clone() /> }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                            {
+                                if self.props.has_content() {
+                                    html! {
+                                        <FeedContent
+                                          content=self.props.content.clone()
+                                          date=self.props.date.clone()
+                                          extra_images=self.props.extra_images.clone()
+                                          extra_text=self.props.extra_text.clone()
+                                          meta=self.props.meta.clone()
+                                          summary=self.props.summary.clone()
+                                        />
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                          </>
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl FeedEventProps {
+    fn derive_classes(&self) -> Vec<String> {
+        cx!(
+            use_str("event"),
+            use_option(&self.class_name)
+        )
+    }
+
+    fn has_content(&self) -> bool {
+        self.content.is_some()
+            || self.date.is_some()
+            || !self.extra_images.is_empty()
+            || self.extra_text.is_some()
+            || self.meta.is_some()
+            || self.summary.is_some()
+    }
+}
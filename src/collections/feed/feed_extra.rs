@@ -0,0 +1,101 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+
+/// A feed can contain an extra content.
+pub struct FeedExtra {
+    props: FeedExtraProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FeedExtraProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// An event can contain additional information like a set of images, by pass their URLs.
+    #[prop_or_default]
+    pub images: Vec<String>,
+    /// An event can contain additional text information.
+    #[prop_or(false)]
+    pub text: bool,
+}
+
+impl Component for FeedExtra {
+    type Message = ();
+    type Properties = FeedExtraProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          <>
+                            {
+                                match self.props.content {
+                                    Some(ref content) => html! { content.clone() },
+                                    None => html! {}
+                                }
+                            }
+                            { for self.props.images.iter().map(|image| html! { <img src=image.clone() /> }) }
+                          </>
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl FeedExtraProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            content,
+            images,
+            text,
+            ..
+        } = self;
+
+        cx!(
+            use_key(!images.is_empty(), "images"),
+            use_key(content.is_some() || *text, "text"),
+            use_str("extra"),
+            use_option(class_name)
+        )
+    }
+}
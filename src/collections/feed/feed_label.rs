@@ -0,0 +1,98 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use crate::collections::Icon;
+
+/// An event can contain an image or icon label.
+pub struct FeedLabel {
+    props: FeedLabelProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FeedLabelProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Add an Icon by pass an <Icon />.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<Icon>,
+    /// Shorthand for the URL of an image.
+    #[prop_or_else(|| None)]
+    pub image: Option<String>,
+}
+
+impl Component for FeedLabel {
+    type Message = ();
+    type Properties = FeedLabelProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          <>
+                            { self.props.icon.clone() }
+                            {
+                                match self.props.image {
+                                    Some(ref image) => html! { <img src=image.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                            {
+                                match self.props.content {
+                                    Some(ref content) => html! { content.clone() },
+                                    None => html! {}
+                                }
+                            }
+                          </>
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl FeedLabelProps {
+    fn derive_classes(&self) -> Vec<String> {
+        cx!(
+            use_str("label"),
+            use_option(&self.class_name)
+        )
+    }
+}
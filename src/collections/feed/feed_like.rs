@@ -0,0 +1,95 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use crate::collections::Icon;
+
+/// A feed can contain a like element.
+pub struct FeedLike {
+    props: FeedLikeProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FeedLikeProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "a".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Add an Icon by pass an <Icon />, defaults to a like icon.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<Icon>,
+}
+
+impl Component for FeedLike {
+    type Message = ();
+    type Properties = FeedLikeProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          <>
+                            {
+                                if self.props.icon.is_empty() {
+                                    html! { <Icon name="like" /> }
+                                } else {
+                                    html! { <>{ self.props.icon.clone() }</> }
+                                }
+                            }
+                            {
+                                match self.props.content {
+                                    Some(ref content) => html! { content.clone() },
+                                    None => html! {}
+                                }
+                            }
+                          </>
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl FeedLikeProps {
+    fn derive_classes(&self) -> Vec<String> {
+        cx!(
+            use_str("like"),
+            use_option(&self.class_name)
+        )
+    }
+}
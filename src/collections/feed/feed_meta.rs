@@ -0,0 +1,94 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use super::FeedLike;
+
+/// A feed can contain a meta.
+pub struct FeedMeta {
+    props: FeedMetaProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FeedMetaProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Shorthand for FeedLike.
+    #[prop_or_else(|| None)]
+    pub like: Option<String>,
+}
+
+impl Component for FeedMeta {
+    type Message = ();
+    type Properties = FeedMetaProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          <>
+                            {
+                                match self.props.like {
+                                    Some(ref like) => html! { <FeedLike content=like.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                            {
+                                match self.props.content {
+                                    Some(ref content) => html! { content.clone() },
+                                    None => html! {}
+                                }
+                            }
+                          </>
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl FeedMetaProps {
+    fn derive_classes(&self) -> Vec<String> {
+        cx!(
+            use_str("meta"),
+            use_option(&self.class_name)
+        )
+    }
+}
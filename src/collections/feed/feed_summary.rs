@@ -0,0 +1,103 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use super::{FeedDate, FeedUser};
+
+/// A feed can contain a summary.
+pub struct FeedSummary {
+    props: FeedSummaryProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FeedSummaryProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Shorthand for FeedDate.
+    #[prop_or_else(|| None)]
+    pub date: Option<String>,
+    /// Shorthand for FeedUser.
+    #[prop_or_else(|| None)]
+    pub user: Option<String>,
+}
+
+impl Component for FeedSummary {
+    type Message = ();
+    type Properties = FeedSummaryProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          <>
+                            {
+                                match self.props.user {
+                                    Some(ref user) => html! { <FeedUser content=user.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                            {
+                                match self.props.content {
+                                    Some(ref content) => html! { content.clone() },
+                                    None => html! {}
+                                }
+                            }
+                            {
+                                match self.props.date {
+                                    Some(ref date) => html! { <FeedDate content=date.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                          </>
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl FeedSummaryProps {
+    fn derive_classes(&self) -> Vec<String> {
+        cx!(
+            use_str("summary"),
+            use_option(&self.class_name)
+        )
+    }
+}
@@ -0,0 +1,21 @@
+mod feed;
+mod feed_content;
+mod feed_date;
+mod feed_event;
+mod feed_extra;
+mod feed_label;
+mod feed_like;
+mod feed_meta;
+mod feed_summary;
+mod feed_user;
+
+pub use feed::*;
+pub use feed_content::*;
+pub use feed_date::*;
+pub use feed_event::*;
+pub use feed_extra::*;
+pub use feed_label::*;
+pub use feed_like::*;
+pub use feed_meta::*;
+pub use feed_summary::*;
+pub use feed_user::*;
\ No newline at end of file
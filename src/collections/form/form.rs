@@ -0,0 +1,167 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+
+/// A Form displays a set of related user input fields in a structured way.
+/// Also see [`FormField`](crate::collections::FormField), [`FormGroup`](crate::collections::FormGroup)
+pub struct Form {
+    link: ComponentLink<Self>,
+    props: FormProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FormWidths {
+    Equal,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FormProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "form".to_string())]
+    pub root: String,
+    /// The HTML form action.
+    #[prop_or_else(|| None)]
+    pub action: Option<String>,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Automatically show any error Message children.
+    #[prop_or(false)]
+    pub error: bool,
+    /// A form can have its color inverted for contrast.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// Automatically show a loading indicator.
+    #[prop_or(false)]
+    pub loading: bool,
+    /// Let the browser submit the form natively instead of preventing it before `on_submit`.
+    #[prop_or(false)]
+    pub native_submit: bool,
+    /// Called on submit.
+    #[prop_or_default]
+    pub on_submit: Callback<FocusEvent>,
+    /// A comment can contain a form to reply to a comment. This may have arbitrary content.
+    #[prop_or(false)]
+    pub reply: bool,
+    /// A form can vary in size.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// Automatically show any success Message children.
+    #[prop_or(false)]
+    pub success: bool,
+    /// A form can prevent itself from stacking on mobile.
+    #[prop_or(false)]
+    pub unstackable: bool,
+    /// Automatically show any warning Message children.
+    #[prop_or(false)]
+    pub warning: bool,
+    /// Forms can automatically divide fields to be equal width.
+    #[prop_or_else(|| None)]
+    pub widths: Option<FormWidths>,
+}
+
+impl Component for Form {
+    type Message = FocusEvent;
+    type Properties = FormProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { link, props, classes }
+    }
+
+    fn update(&mut self, event: Self::Message) -> ShouldRender {
+        if !self.props.native_submit {
+            event.prevent_default();
+        }
+        self.props.on_submit.emit(event);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+              action=self.props.action.clone()
+              onsubmit=self.link.callback(|e| e)
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl FormProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            error,
+            inverted,
+            loading,
+            reply,
+            size,
+            success,
+            unstackable,
+            warning,
+            widths,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(size),
+            use_key(*error, "error"),
+            use_key(*inverted, "inverted"),
+            use_key(*loading, "loading"),
+            use_key(*reply, "reply"),
+            use_key(*success, "success"),
+            use_key(*unstackable, "unstackable"),
+            use_key(*warning, "warning"),
+            use_option_and_key(widths, "width"),
+            use_str("form"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<FormWidths> for &'static str {
+    fn from(f: FormWidths) -> Self {
+        use FormWidths::*;
+
+        match f {
+            Equal => "equal",
+        }
+    }
+}
+
+impl AsRef<str> for FormWidths {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
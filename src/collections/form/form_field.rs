@@ -0,0 +1,154 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use crate::collections::Label;
+use crate::collections::label::{LabelPointing, LabelProps};
+
+/// A field is a form element containing a label and an input.
+/// Also see [`Form`](crate::collections::Form), [`Label`](crate::collections::Label)
+pub struct FormField {
+    props: FormFieldProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FormFieldProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// The control of the field, e.g. an <Input />.
+    #[prop_or_else(|| None)]
+    pub control: Option<Html>,
+    /// Individual fields may be disabled.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// Individual fields may display an error state along with a message, rendered as a prompt Label.
+    #[prop_or_else(|| Either::Left(false))]
+    pub error: Either<bool, LabelProps>,
+    /// The id of the control, referenced by the label.
+    #[prop_or_else(|| None)]
+    pub id: Option<String>,
+    /// A field can have its label next to instead of above it.
+    #[prop_or(false)]
+    pub inline: bool,
+    /// Shorthand for the html label of the control.
+    #[prop_or_else(|| None)]
+    pub label: Option<String>,
+    /// A field can show that input is mandatory.
+    #[prop_or(false)]
+    pub required: bool,
+    /// A field can specify its width in grid columns.
+    #[prop_or_else(|| None)]
+    pub width: Option<sui::Widths>,
+}
+
+impl Component for FormField {
+    type Message = ();
+    type Properties = FormFieldProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if self.props.control.is_none() && self.props.label.is_none() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                >
+                  {
+                      if !self.props.children.is_empty() {
+                          html! { <>{ self.props.children.clone() }</> }
+                      } else {
+                          match self.props.content {
+                              Some(ref content) => html! { content.clone() },
+                              None => html! {}
+                          }
+                      }
+                  }
+                </@>
+            }
+        }
+
+        let (error_before, error_after) = match self.props.error {
+            Either::Right(ref error) => {
+                let mut props = error.clone();
+                props.prompt = true;
+                if props.pointing == Either::Left(false) || props.pointing == Either::Left(true) {
+                    props.pointing = Either::Right(LabelPointing::Above);
+                }
+                let label = html! { <Label with props.clone() /> };
+                match props.pointing {
+                    Either::Right(LabelPointing::Below) | Either::Right(LabelPointing::Right) => (label, html! {}),
+                    _ => (html! {}, label),
+                }
+            }
+            Either::Left(_) => (html! {}, html! {}),
+        };
+        let label = match self.props.label {
+            Some(ref label) => html! { <label for=self.props.id.clone()>{ label.clone() }</label> },
+            None => html! {}
+        };
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              { label }
+              { error_before }
+              { self.props.control.clone().unwrap_or_default() }
+              { error_after }
+            </@>
+        }
+    }
+}
+
+impl FormFieldProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            disabled,
+            error,
+            inline,
+            required,
+            width,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*disabled, "disabled"),
+            use_key(!matches!(error, Either::Left(false)), "error"),
+            use_key(*inline, "inline"),
+            use_key(*required, "required"),
+            use_option_and_key(width, "wide"),
+            use_str("field"),
+            use_option(class_name)
+        )
+    }
+}
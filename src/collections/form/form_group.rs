@@ -0,0 +1,111 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::FormWidths;
+
+/// A set of fields can appear grouped together.
+pub struct FormGroup {
+    props: FormGroupProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FormGroupProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Fields can show related choices.
+    #[prop_or(false)]
+    pub grouped: bool,
+    /// Multiple fields may be inline in a row.
+    #[prop_or(false)]
+    pub inline: bool,
+    /// A form group can prevent itself from stacking on mobile.
+    #[prop_or(false)]
+    pub unstackable: bool,
+    /// Fields Groups can specify their width in grid columns or automatically divide fields to be equal width.
+    #[prop_or_else(|| None)]
+    pub widths: Option<Either<sui::Widths, FormWidths>>,
+}
+
+impl Component for FormGroup {
+    type Message = ();
+    type Properties = FormGroupProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl FormGroupProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            grouped,
+            inline,
+            unstackable,
+            widths,
+            ..
+        } = self;
+
+        let mut widths_class = match widths {
+            Some(Either::Left(count)) => use_option(&Some(*count)),
+            Some(Either::Right(widths)) => use_option_and_key(&Some(*widths), "width"),
+            None => vec![],
+        };
+
+        cx!(
+            use_key(*grouped, "grouped"),
+            use_key(*inline, "inline"),
+            use_key(*unstackable, "unstackable"),
+            widths_class,
+            use_str("fields"),
+            use_option(class_name)
+        )
+    }
+}
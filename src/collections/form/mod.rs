@@ -0,0 +1,7 @@
+mod form;
+mod form_field;
+mod form_group;
+
+pub use form::*;
+pub use form_field::*;
+pub use form_group::*;
\ No newline at end of file
@@ -0,0 +1,329 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+
+/// A grid is used to harmonize negative space in a layout.
+/// Also see [`GridRow`](crate::collections::GridRow), [`GridColumn`](crate::collections::GridColumn)
+pub struct Grid {
+    props: GridProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GridCelled {
+    Internally,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GridColumns {
+    Equal,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GridDivided {
+    Vertically,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GridOnly {
+    Computer,
+    LargeScreen,
+    Mobile,
+    Tablet,
+    Widescreen,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GridPadded {
+    Horizontally,
+    Vertically,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GridRelaxed {
+    Very,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GridReversed {
+    Computer,
+    ComputerVertically,
+    Mobile,
+    MobileVertically,
+    Tablet,
+    TabletVertically,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct GridProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// A grid can have rows divided into cells.
+    #[prop_or_else(|| Either::Left(false))]
+    pub celled: Either<bool, GridCelled>,
+    /// A grid can have its columns centered.
+    #[prop_or(false)]
+    pub centered: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Represents column count per row in Grid.
+    #[prop_or_else(|| None)]
+    pub columns: Option<Either<sui::Widths, GridColumns>>,
+    /// A grid can be combined with a container to use the available layout and alignment.
+    #[prop_or(false)]
+    pub container: bool,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A grid can have dividers between its columns.
+    #[prop_or_else(|| Either::Left(false))]
+    pub divided: Either<bool, GridDivided>,
+    /// A grid can double its column width on tablet and mobile sizes.
+    #[prop_or(false)]
+    pub doubling: bool,
+    /// A grid's colors can be inverted.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// A grid can preserve its vertical and horizontal gutters on first and last columns.
+    #[prop_or_else(|| Either::Left(false))]
+    pub padded: Either<bool, GridPadded>,
+    /// A grid can increase its gutters to allow for more negative space.
+    #[prop_or_else(|| Either::Left(false))]
+    pub relaxed: Either<bool, GridRelaxed>,
+    /// A grid can specify that its columns should reverse order at different device sizes.
+    #[prop_or_default]
+    pub reversed: Vec<GridReversed>,
+    /// A grid can have its columns stack on-top of each other after reaching mobile breakpoints.
+    #[prop_or(false)]
+    pub stackable: bool,
+    /// A grid can stretch its contents to take up the entire grid height.
+    #[prop_or(false)]
+    pub stretched: bool,
+    /// A grid can specify its text alignment.
+    #[prop_or_else(|| None)]
+    pub text_align: Option<sui::TextAlign>,
+    /// A grid can specify its vertical alignment to have all its columns vertically centered.
+    #[prop_or_else(|| None)]
+    pub vertical_align: Option<sui::VerticalAlign>,
+}
+
+impl Component for Grid {
+    type Message = ();
+    type Properties = GridProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl GridProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            celled,
+            centered,
+            class_name,
+            columns,
+            container,
+            divided,
+            doubling,
+            inverted,
+            padded,
+            relaxed,
+            reversed,
+            stackable,
+            stretched,
+            text_align,
+            vertical_align,
+            ..
+        } = self;
+
+        let mut columns_class = match columns {
+            Some(Either::Left(widths)) => use_option_and_key(&Some(*widths), "column"),
+            Some(Either::Right(columns)) => use_option_and_key(&Some(*columns), "width"),
+            None => vec![],
+        };
+
+        cx!(
+            use_str("ui"),
+            use_key(*centered, "centered"),
+            use_key(*container, "container"),
+            use_key(*doubling, "doubling"),
+            use_key(*inverted, "inverted"),
+            use_key(*stackable, "stackable"),
+            use_key(*stretched, "stretched"),
+            use_key_or_option_and_key(celled, "celled"),
+            use_key_or_option_and_key(divided, "divided"),
+            use_key_or_option_and_key(padded, "padded"),
+            use_key_or_option_and_key(relaxed, "relaxed"),
+            use_multiple_prop(reversed, "reversed"),
+            use_option_text_align(text_align),
+            use_option_and_key(vertical_align, "aligned"),
+            columns_class,
+            use_str("grid"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<GridCelled> for &'static str {
+    fn from(g: GridCelled) -> Self {
+        use GridCelled::*;
+
+        match g {
+            Internally => "internally",
+        }
+    }
+}
+
+impl AsRef<str> for GridCelled {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<GridColumns> for &'static str {
+    fn from(g: GridColumns) -> Self {
+        use GridColumns::*;
+
+        match g {
+            Equal => "equal",
+        }
+    }
+}
+
+impl AsRef<str> for GridColumns {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<GridDivided> for &'static str {
+    fn from(g: GridDivided) -> Self {
+        use GridDivided::*;
+
+        match g {
+            Vertically => "vertically",
+        }
+    }
+}
+
+impl AsRef<str> for GridDivided {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<GridOnly> for &'static str {
+    fn from(g: GridOnly) -> Self {
+        use GridOnly::*;
+
+        match g {
+            Computer => "computer",
+            LargeScreen => "large screen",
+            Mobile => "mobile",
+            Tablet => "tablet",
+            Widescreen => "widescreen",
+        }
+    }
+}
+
+impl AsRef<str> for GridOnly {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<GridPadded> for &'static str {
+    fn from(g: GridPadded) -> Self {
+        use GridPadded::*;
+
+        match g {
+            Horizontally => "horizontally",
+            Vertically => "vertically",
+        }
+    }
+}
+
+impl AsRef<str> for GridPadded {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<GridRelaxed> for &'static str {
+    fn from(g: GridRelaxed) -> Self {
+        use GridRelaxed::*;
+
+        match g {
+            Very => "very",
+        }
+    }
+}
+
+impl AsRef<str> for GridRelaxed {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<GridReversed> for &'static str {
+    fn from(g: GridReversed) -> Self {
+        use GridReversed::*;
+
+        match g {
+            Computer => "computer",
+            ComputerVertically => "computer vertically",
+            Mobile => "mobile",
+            MobileVertically => "mobile vertically",
+            Tablet => "tablet",
+            TabletVertically => "tablet vertically",
+        }
+    }
+}
+
+impl AsRef<str> for GridReversed {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
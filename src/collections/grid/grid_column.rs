@@ -0,0 +1,144 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::GridOnly;
+
+/// A column sub-component for Grid.
+pub struct GridColumn {
+    props: GridColumnProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct GridColumnProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A grid column can be colored.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// A column can specify a width for a computer.
+    #[prop_or_else(|| None)]
+    pub computer: Option<sui::Widths>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A column can sit flush against the left or right edge of a row.
+    #[prop_or_else(|| None)]
+    pub floated: Option<sui::Float>,
+    /// A column can specify a width for a large screen device.
+    #[prop_or_else(|| None)]
+    pub large_screen: Option<sui::Widths>,
+    /// A column can specify a width for a mobile device.
+    #[prop_or_else(|| None)]
+    pub mobile: Option<sui::Widths>,
+    /// A column can appear only for a specific device, or screen sizes.
+    #[prop_or_default]
+    pub only: Vec<GridOnly>,
+    /// A column can stretch its contents to take up the entire grid or row height.
+    #[prop_or(false)]
+    pub stretched: bool,
+    /// A column can specify a width for a tablet device.
+    #[prop_or_else(|| None)]
+    pub tablet: Option<sui::Widths>,
+    /// A column can specify its text alignment.
+    #[prop_or_else(|| None)]
+    pub text_align: Option<sui::TextAlign>,
+    /// A column can specify its vertical alignment to have all its columns vertically centered.
+    #[prop_or_else(|| None)]
+    pub vertical_align: Option<sui::VerticalAlign>,
+    /// A column can specify a width for a wide screen device.
+    #[prop_or_else(|| None)]
+    pub widescreen: Option<sui::Widths>,
+    /// Represents width of column.
+    #[prop_or_else(|| None)]
+    pub width: Option<sui::Widths>,
+}
+
+impl Component for GridColumn {
+    type Message = ();
+    type Properties = GridColumnProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl GridColumnProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            color,
+            computer,
+            floated,
+            large_screen,
+            mobile,
+            only,
+            stretched,
+            tablet,
+            text_align,
+            vertical_align,
+            widescreen,
+            width,
+            ..
+        } = self;
+
+        cx!(
+            use_option(color),
+            use_key(*stretched, "stretched"),
+            use_multiple_prop(only, "only"),
+            use_option_text_align(text_align),
+            use_option_and_key(floated, "floated"),
+            use_option_and_key(vertical_align, "aligned"),
+            use_option_and_key(computer, "wide computer"),
+            use_option_and_key(large_screen, "wide large screen"),
+            use_option_and_key(mobile, "wide mobile"),
+            use_option_and_key(tablet, "wide tablet"),
+            use_option_and_key(widescreen, "wide widescreen"),
+            use_option_and_key(width, "wide"),
+            use_str("column"),
+            use_option(class_name)
+        )
+    }
+}
@@ -0,0 +1,136 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::{GridColumns, GridOnly, GridReversed};
+
+/// A row sub-component for Grid.
+pub struct GridRow {
+    props: GridRowProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct GridRowProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// A row can have its columns centered.
+    #[prop_or(false)]
+    pub centered: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A grid row can be colored.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// Represents column count per line in Row.
+    #[prop_or_else(|| None)]
+    pub columns: Option<Either<sui::Widths, GridColumns>>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A row can have dividers between its columns.
+    #[prop_or(false)]
+    pub divided: bool,
+    /// A row can appear only for a specific device, or screen sizes.
+    #[prop_or_default]
+    pub only: Vec<GridOnly>,
+    /// A row can specify that its columns should reverse order at different device sizes.
+    #[prop_or_default]
+    pub reversed: Vec<GridReversed>,
+    /// A row can stretch its contents to take up the entire column height.
+    #[prop_or(false)]
+    pub stretched: bool,
+    /// A row can specify its text alignment.
+    #[prop_or_else(|| None)]
+    pub text_align: Option<sui::TextAlign>,
+    /// A row can specify its vertical alignment to have all its columns vertically centered.
+    #[prop_or_else(|| None)]
+    pub vertical_align: Option<sui::VerticalAlign>,
+}
+
+impl Component for GridRow {
+    type Message = ();
+    type Properties = GridRowProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl GridRowProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            centered,
+            class_name,
+            color,
+            columns,
+            divided,
+            only,
+            reversed,
+            stretched,
+            text_align,
+            vertical_align,
+            ..
+        } = self;
+
+        let mut columns_class = match columns {
+            Some(Either::Left(widths)) => use_option_and_key(&Some(*widths), "column"),
+            Some(Either::Right(columns)) => use_option_and_key(&Some(*columns), "width"),
+            None => vec![],
+        };
+
+        cx!(
+            use_option(color),
+            use_key(*centered, "centered"),
+            use_key(*divided, "divided"),
+            use_key(*stretched, "stretched"),
+            use_multiple_prop(only, "only"),
+            use_multiple_prop(reversed, "reversed"),
+            use_option_text_align(text_align),
+            use_option_and_key(vertical_align, "aligned"),
+            columns_class,
+            use_str("row"),
+            use_option(class_name)
+        )
+    }
+}
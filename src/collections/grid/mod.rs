@@ -0,0 +1,7 @@
+mod grid;
+mod grid_column;
+mod grid_row;
+
+pub use grid::*;
+pub use grid_column::*;
+pub use grid_row::*;
\ No newline at end of file
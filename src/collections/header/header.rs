@@ -0,0 +1,210 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use crate::collections::Icon;
+use crate::collections::image::{Image, ImageProps};
+use super::{HeaderContent, HeaderSubheader};
+
+/// A header provides a short summary of content.
+pub struct Header {
+    props: HeaderProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HeaderAttachedPosition {
+    Top,
+    Bottom,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct HeaderProps {
+    /// An html element type to render as root element, e.g. `h1` to `h6`.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Attach header to other content, like a segment.
+    #[prop_or_else(|| Either::Left(false))]
+    pub attached: Either<bool, HeaderAttachedPosition>,
+    /// Format header to appear inside a content block.
+    #[prop_or(false)]
+    pub block: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Color of the header.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Show that the header is inactive.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// Divide header from the content below it.
+    #[prop_or(false)]
+    pub dividing: bool,
+    /// Header can sit to the left or right of other content.
+    #[prop_or_else(|| None)]
+    pub floated: Option<sui::Float>,
+    /// Add an Icon by pass an <Icon />.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<Icon>,
+    /// Format the header to emphasize an icon, rendered above its content.
+    #[prop_or(false)]
+    pub icon_header: bool,
+    /// Add an Image by pass an image shorthand, or use `true` to format the header for an image.
+    #[prop_or_else(|| Either::Left(false))]
+    pub image: Either<bool, ImageProps>,
+    /// Inverts the color of the header for dark backgrounds.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// Content headings are sized with em and are based on the font-size of their container.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// Headers may be formatted to label smaller or de-emphasized content.
+    #[prop_or(false)]
+    pub sub: bool,
+    /// Shorthand for HeaderSubheader.
+    #[prop_or_else(|| None)]
+    pub subheader: Option<String>,
+    /// Align header content.
+    #[prop_or_else(|| None)]
+    pub text_align: Option<sui::TextAlign>,
+}
+
+impl Component for Header {
+    type Message = ();
+    type Properties = HeaderProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                >
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        let content = match self.props.content {
+            Some(ref content) => html! { content.clone() },
+            None => html! {}
+        };
+        let subheader = match self.props.subheader {
+            Some(ref subheader) => html! { <HeaderSubheader content=subheader.clone() /> },
+            None => html! {}
+        };
+
+        let image = match self.props.image {
+            Either::Right(ref image) => Some(html! { <Image with image.clone() /> }),
+            Either::Left(_) => None,
+        };
+        if self.props.icon.is_empty() && image.is_none() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                >
+                  { content }
+                  { subheader }
+                </@>
+            }
+        }
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              { self.props.icon.clone() }
+              { image.unwrap_or_default() }
+              {
+                  if self.props.content.is_some() || self.props.subheader.is_some() {
+                      html! { <HeaderContent>{ content }{ subheader }</HeaderContent> }
+                  } else {
+                      html! {}
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl HeaderProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            attached,
+            block,
+            class_name,
+            color,
+            disabled,
+            dividing,
+            floated,
+            icon_header,
+            image,
+            inverted,
+            size,
+            sub,
+            text_align,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(color),
+            use_option(size),
+            use_key(*block, "block"),
+            use_key(*disabled, "disabled"),
+            use_key(*dividing, "dividing"),
+            use_option_and_key(floated, "floated"),
+            use_key(*icon_header, "icon"),
+            use_key(*image == Either::Left(true), "image"),
+            use_key(*inverted, "inverted"),
+            use_key(*sub, "sub"),
+            use_key_or_option_and_key(attached, "attached"),
+            use_option_text_align(text_align),
+            use_str("header"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<HeaderAttachedPosition> for &'static str {
+    fn from(h: HeaderAttachedPosition) -> Self {
+        use HeaderAttachedPosition::*;
+
+        match h {
+            Top => "top",
+            Bottom => "bottom",
+        }
+    }
+}
+
+impl AsRef<str> for HeaderAttachedPosition {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
@@ -0,0 +1,7 @@
+mod header;
+mod header_content;
+mod header_subheader;
+
+pub use header::*;
+pub use header_content::*;
+pub use header_subheader::*;
\ No newline at end of file
@@ -0,0 +1,234 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use crate::collections::Label;
+
+/// An image is a graphic representation of something.
+/// Also see [`ImageGroup`](crate::collections::image::ImageGroup)
+pub struct Image {
+    props: ImageProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageSpaced {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ImageProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "img".to_string())]
+    pub root: String,
+    /// Alternate text for the image specified.
+    #[prop_or_else(|| None)]
+    pub alt: Option<String>,
+    /// An image may be formatted to appear inline with text as an avatar.
+    #[prop_or(false)]
+    pub avatar: bool,
+    /// An image may include a border to emphasize the edges of white or transparent content.
+    #[prop_or(false)]
+    pub bordered: bool,
+    /// An image can appear centered in a content block.
+    #[prop_or(false)]
+    pub centered: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// An image may appear circular.
+    #[prop_or(false)]
+    pub circular: bool,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// An image can show that it is disabled and cannot be selected.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// An image can sit to the left or right of other content.
+    #[prop_or_else(|| None)]
+    pub floated: Option<sui::Float>,
+    /// An image can take up the width of its container.
+    #[prop_or(false)]
+    pub fluid: bool,
+    /// An image can be hidden.
+    #[prop_or(false)]
+    pub hidden: bool,
+    /// Renders the Image as an <a> tag with this href.
+    #[prop_or_else(|| None)]
+    pub href: Option<String>,
+    /// An image may appear inline.
+    #[prop_or(false)]
+    pub inline: bool,
+    /// Add a Label by pass a <Label />.
+    #[prop_or_default]
+    pub label: ChildrenWithProps<Label>,
+    /// An image may appear rounded.
+    #[prop_or(false)]
+    pub rounded: bool,
+    /// An image may appear at different sizes.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// An image can specify that it needs an additional spacing to separate it from nearby content.
+    #[prop_or_else(|| Either::Left(false))]
+    pub spaced: Either<bool, ImageSpaced>,
+    /// Specifies the URL of the image.
+    #[prop_or_else(|| None)]
+    pub src: Option<String>,
+    /// Whether or not to add the ui className.
+    #[prop_or(true)]
+    pub ui: bool,
+    /// An image can specify its vertical alignment.
+    #[prop_or_else(|| None)]
+    pub vertical_align: Option<sui::VerticalAlign>,
+    /// An image can render wrapped in a `div.ui.image` as alternative HTML markup.
+    #[prop_or(false)]
+    pub wrapped: bool,
+}
+
+impl Component for Image {
+    type Message = ();
+    type Properties = ImageProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let root_element = self.props.get_element_type();
+
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ root_element }
+                  class=classes!(self.classes.as_slice())
+                  href=self.props.href.clone()
+                >
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        if let Some(ref content) = self.props.content {
+            return html! {
+                <@{ root_element }
+                  class=classes!(self.classes.as_slice())
+                  href=self.props.href.clone()
+                >
+                  { content.clone() }
+                </@>
+            }
+        }
+
+        if root_element == "img" {
+            return html! {
+                <img
+                  class=classes!(self.classes.as_slice())
+                  src=self.props.src.clone()
+                  alt=self.props.alt.clone()
+                />
+            }
+        }
+
+        html! {
+            <@{ root_element }
+              class=classes!(self.classes.as_slice())
+              href=self.props.href.clone()
+            >
+              { self.props.label.clone() }
+              <img src=self.props.src.clone() alt=self.props.alt.clone() />
+            </@>
+        }
+    }
+}
+
+impl ImageProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            avatar,
+            bordered,
+            centered,
+            circular,
+            class_name,
+            disabled,
+            floated,
+            fluid,
+            hidden,
+            inline,
+            rounded,
+            size,
+            spaced,
+            ui,
+            vertical_align,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*ui, "ui"),
+            use_option(size),
+            use_key(*avatar, "avatar"),
+            use_key(*bordered, "bordered"),
+            use_key(*circular, "circular"),
+            use_key(*centered, "centered"),
+            use_key(*disabled, "disabled"),
+            use_key(*fluid, "fluid"),
+            use_key(*hidden, "hidden"),
+            use_key(*inline, "inline"),
+            use_key(*rounded, "rounded"),
+            use_key_or_option_and_key(spaced, "spaced"),
+            use_option_and_key(floated, "floated"),
+            use_option_and_key(vertical_align, "aligned"),
+            use_str("image"),
+            use_option(class_name)
+        )
+    }
+
+    fn get_element_type(&self) -> String {
+        if self.root != "img" {
+            return self.root.clone()
+        }
+        if self.href.is_some() {
+            return "a".to_string()
+        }
+        if self.wrapped || !self.label.is_empty() || !self.children.is_empty() || self.content.is_some() {
+            return "div".to_string()
+        }
+        self.root.clone()
+    }
+}
+
+impl From<ImageSpaced> for &'static str {
+    fn from(i: ImageSpaced) -> Self {
+        use ImageSpaced::*;
+
+        match i {
+            Left => "left",
+            Right => "right",
+        }
+    }
+}
+
+impl AsRef<str> for ImageSpaced {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
@@ -0,0 +1,5 @@
+mod image;
+mod image_group;
+
+pub use image::*;
+pub use image_group::*;
\ No newline at end of file
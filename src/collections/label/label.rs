@@ -4,6 +4,7 @@ use yew::prelude::*;
 use crate::{cx, sui};
 use crate::helper::*;
 use crate::collections::Icon;
+use crate::collections::image::{Image, ImageProps};
 use crate::collections::label::LabelDetail;
 
 /// A label displays content classification.
@@ -91,10 +92,9 @@ pub struct LabelProps {
     /// Shorthand for Icon.
     #[prop_or_default]
     pub icon: ChildrenWithProps<Icon>,
-
-    // TODO: /// A label can be formatted to emphasize an image or prop can be used as shorthand for Image.
-    // image: oneOfType([bool, customitemShorthand]),
-
+    /// A label can be formatted to emphasize an image or prop can be used as shorthand for Image.
+    #[prop_or_else(|| Either::Left(false))]
+    pub image: Either<bool, ImageProps>,
     /// Called on click.
     #[prop_or_default]
     pub on_click: Callback<MouseEvent>,
@@ -187,7 +187,11 @@ impl Component for Label {
               onclick=self.link.callback(|e| LabelEvent::Click(e))
             >
               {self.props.icon.clone()}
-              // TODO: {typeof image !== 'boolean' && Image.create(image, { autoGenerateKey: false })}
+              { match self.props.image {
+                    Either::Right(ref image) => html! { <Image with image.clone() /> },
+                    Either::Left(_) => html! {}
+                }
+              }
               { match self.props.content {
                     Some(ref content) => html! { content.clone() },
                     None => html! {}
@@ -212,7 +216,7 @@ impl LabelProps {
             corner,
             floating,
             horizontal,
-            // TODO: image,
+            image,
             pointing,
             prompt,
             ribbon,
@@ -243,7 +247,7 @@ impl LabelProps {
             use_key(*circular, "circular"),
             use_key(*floating, "floating"),
             use_key(*horizontal, "horizontal"),
-            // TODO: use_key(image == true, "image",
+            use_key(*image == Either::Left(true), "image"),
             use_key(*prompt, "prompt"),
             use_key(*tag, "tag"),
             use_key_or_option_and_key(corner, "corner"),
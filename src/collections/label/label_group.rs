@@ -0,0 +1,129 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use crate::collections::label::{Label, LabelProps};
+
+/// A label can be grouped.
+pub struct LabelGroup {
+    props: LabelGroupProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct LabelGroupProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Labels can share shapes.
+    #[prop_or(false)]
+    pub circular: bool,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Label group can share colors together.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Array of shorthand Label values.
+    #[prop_or_default]
+    pub labels: Vec<LabelProps>,
+    /// Label group can share sizes together.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// Label group can share tag formatting.
+    #[prop_or(false)]
+    pub tag: bool,
+}
+
+impl Component for LabelGroup {
+    type Message = ();
+    type Properties = LabelGroupProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> bool {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                >
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              { for self.props.labels.iter().map(|label| self.render_label(label)) }
+              { match self.props.content {
+                    Some(ref content) => html! { content.clone() },
+                    None => html! {}
+                }
+              }
+            </@>
+        }
+    }
+}
+
+impl LabelGroup {
+    fn render_label(&self, label: &LabelProps) -> Html {
+        let mut props = label.clone();
+        if props.color.is_none() {
+            props.color = self.props.color;
+        }
+        if props.size.is_none() {
+            props.size = self.props.size;
+        }
+        props.circular |= self.props.circular;
+        props.tag |= self.props.tag;
+        html! { <Label with props /> }
+    }
+}
+
+impl LabelGroupProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            circular,
+            class_name,
+            color,
+            size,
+            tag,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(color),
+            use_option(size),
+            use_key(*circular, "circular"),
+            use_key(*tag, "tag"),
+            use_str("labels"),
+            use_option(class_name)
+        )
+    }
+}
@@ -1,5 +1,7 @@
 pub mod label;
 pub mod label_detail;
+pub mod label_group;
 
 pub use label::*;
-pub use label_detail::*;
\ No newline at end of file
+pub use label_detail::*;
+pub use label_group::*;
\ No newline at end of file
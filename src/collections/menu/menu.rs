@@ -0,0 +1,357 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::{MenuItem, MenuItemProps};
+
+/// A menu displays grouped navigation actions.
+/// Also see [`MenuItem`](crate::collections::MenuItem), [`MenuHeader`](crate::collections::MenuHeader), [`MenuMenu`](crate::collections::MenuMenu)
+pub struct Menu {
+    link: ComponentLink<Self>,
+    props: MenuProps,
+    classes: Vec<String>,
+    active_index: Option<usize>,
+}
+
+pub enum MenuMsg {
+    ItemClick(MouseEvent, usize, Either<usize, String>),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MenuAttachedPosition {
+    Top,
+    Bottom,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MenuFixed {
+    Left,
+    Right,
+    Bottom,
+    Top,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MenuFloated {
+    Right
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MenuIcon {
+    Labeled
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MenuPosition {
+    Left,
+    Right,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MenuTabular {
+    Right
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct MenuProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Index of the currently active item, `Some(None)` for none, the menu tracks it by itself when unset.
+    #[prop_or_else(|| None)]
+    pub active_index: Option<Option<usize>>,
+    /// A menu may be attached to other content segments.
+    #[prop_or_else(|| Either::Left(false))]
+    pub attached: Either<bool, MenuAttachedPosition>,
+    /// A menu item or menu can have no borders.
+    #[prop_or(false)]
+    pub borderless: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Additional colors can be specified.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// A menu can take up only the space necessary to fit its content.
+    #[prop_or(false)]
+    pub compact: bool,
+    /// Initial index of the active item when `active_index` is unset.
+    #[prop_or_else(|| None)]
+    pub default_active_index: Option<usize>,
+    /// A menu can be fixed to a side of its context.
+    #[prop_or_else(|| None)]
+    pub fixed: Option<MenuFixed>,
+    /// A menu can be floated.
+    #[prop_or_else(|| Either::Left(false))]
+    pub floated: Either<bool, MenuFloated>,
+    /// A vertical menu may take the size of its container.
+    #[prop_or(false)]
+    pub fluid: bool,
+    /// A menu may have just icons (bool) or labeled icons.
+    #[prop_or_else(|| Either::Left(false))]
+    pub icon: Either<bool, MenuIcon>,
+    /// A menu may have its colors inverted to show greater contrast.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// Shorthand array of props for Menu.
+    #[prop_or_default]
+    pub items: Vec<MenuItemProps>,
+    /// Called when a shorthand item is clicked, with the name of the item or its index if it has no name.
+    #[prop_or_default]
+    pub on_item_click: Callback<(MouseEvent, Either<usize, String>)>,
+    /// A pagination menu is specially formatted to present links to pages of content.
+    #[prop_or(false)]
+    pub pagination: bool,
+    /// A menu can point to show its relationship to nearby content.
+    #[prop_or(false)]
+    pub pointing: bool,
+    /// A menu can adjust its appearance to de-emphasize its contents.
+    #[prop_or(false)]
+    pub secondary: bool,
+    /// A menu can vary in size.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// A menu can stack at mobile resolutions.
+    #[prop_or(false)]
+    pub stackable: bool,
+    /// A menu can be formatted to show tabs of information.
+    #[prop_or_else(|| Either::Left(false))]
+    pub tabular: Either<bool, MenuTabular>,
+    /// A menu can be formatted for text content.
+    #[prop_or(false)]
+    pub text: bool,
+    /// A vertical menu displays elements vertically.
+    #[prop_or(false)]
+    pub vertical: bool,
+    /// A menu can have its items divided evenly.
+    #[prop_or_else(|| None)]
+    pub widths: Option<sui::Widths>,
+}
+
+impl Component for Menu {
+    type Message = MenuMsg;
+    type Properties = MenuProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        let active_index = props.active_index.unwrap_or(props.default_active_index);
+        Self { link, props, classes, active_index }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let MenuMsg::ItemClick(event, index, key) = msg;
+        if let Some(item) = self.props.items.get(index) {
+            item.on_click.emit((event.clone(), key.clone()));
+        }
+        self.props.on_item_click.emit((event, key));
+
+        if self.props.active_index.is_none() && self.active_index != Some(index) {
+            self.active_index = Some(index);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            match props.active_index {
+                Some(active_index) => self.active_index = active_index,
+                None if self.props.active_index.is_some() => self.active_index = props.default_active_index,
+                None => {}
+            }
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          for self.props.items
+                              .iter()
+                              .enumerate()
+                              .map(|(index, item)| self.render_item(index, item))
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl Menu {
+    fn render_item(&self, index: usize, item: &MenuItemProps) -> Html {
+        let mut props = item.clone();
+        props.index = index;
+        props.active = self.active_index == Some(index);
+        props.on_click = self.link.callback(move |(event, key)| MenuMsg::ItemClick(event, index, key));
+        html! { <MenuItem with props /> }
+    }
+}
+
+impl MenuProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            attached,
+            borderless,
+            class_name,
+            color,
+            compact,
+            fixed,
+            floated,
+            fluid,
+            icon,
+            inverted,
+            pagination,
+            pointing,
+            secondary,
+            size,
+            stackable,
+            tabular,
+            text,
+            vertical,
+            widths,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(color),
+            use_option(size),
+            use_key(*borderless, "borderless"),
+            use_key(*compact, "compact"),
+            use_key(*fluid, "fluid"),
+            use_key(*inverted, "inverted"),
+            use_key(*pagination, "pagination"),
+            use_key(*pointing, "pointing"),
+            use_key(*secondary, "secondary"),
+            use_key(*stackable, "stackable"),
+            use_key(*text, "text"),
+            use_key(*vertical, "vertical"),
+            use_key_or_option_and_key(attached, "attached"),
+            use_key_or_option_and_key(floated, "floated"),
+            use_key_or_option_and_key(icon, "icon"),
+            use_key_or_option_and_key(tabular, "tabular"),
+            use_option_and_key(fixed, "fixed"),
+            use_option_and_key(widths, "item"),
+            use_option(class_name),
+            use_str("menu")
+        )
+    }
+}
+
+impl From<MenuAttachedPosition> for &'static str {
+    fn from(m: MenuAttachedPosition) -> Self {
+        use MenuAttachedPosition::*;
+
+        match m {
+            Top => "top",
+            Bottom => "bottom",
+        }
+    }
+}
+
+impl AsRef<str> for MenuAttachedPosition {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<MenuFixed> for &'static str {
+    fn from(m: MenuFixed) -> Self {
+        use MenuFixed::*;
+
+        match m {
+            Left => "left",
+            Right => "right",
+            Bottom => "bottom",
+            Top => "top",
+        }
+    }
+}
+
+impl AsRef<str> for MenuFixed {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<MenuFloated> for &'static str {
+    fn from(m: MenuFloated) -> Self {
+        use MenuFloated::*;
+
+        match m {
+            Right => "right",
+        }
+    }
+}
+
+impl AsRef<str> for MenuFloated {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<MenuIcon> for &'static str {
+    fn from(m: MenuIcon) -> Self {
+        use MenuIcon::*;
+
+        match m {
+            Labeled => "labeled",
+        }
+    }
+}
+
+impl AsRef<str> for MenuIcon {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<MenuPosition> for &'static str {
+    fn from(m: MenuPosition) -> Self {
+        use MenuPosition::*;
+
+        match m {
+            Left => "left",
+            Right => "right",
+        }
+    }
+}
+
+impl AsRef<str> for MenuPosition {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<MenuTabular> for &'static str {
+    fn from(m: MenuTabular) -> Self {
+        use MenuTabular::*;
+
+        match m {
+            Right => "right",
+        }
+    }
+}
+
+impl AsRef<str> for MenuTabular {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
@@ -0,0 +1,182 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use crate::collections::Icon;
+use super::MenuPosition;
+
+/// A menu can contain an item.
+pub struct MenuItem {
+    link: ComponentLink<Self>,
+    props: MenuItemProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MenuItemFitted {
+    Horizontally,
+    Vertically,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct MenuItemProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "a".to_string())]
+    pub root: String,
+    /// A menu item can be active.
+    #[prop_or(false)]
+    pub active: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Additional colors can be specified.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A menu item can be disabled.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// A menu item or menu can remove element padding, vertically or horizontally.
+    #[prop_or_else(|| Either::Left(false))]
+    pub fitted: Either<bool, MenuItemFitted>,
+    /// A menu item may include a header or may itself be a header.
+    #[prop_or(false)]
+    pub header: bool,
+    /// Render as an `a` tag and adds the href attribute.
+    #[prop_or_else(|| None)]
+    pub href: Option<String>,
+    /// Add an Icon by pass an <Icon />.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<Icon>,
+    /// Position of the item in its menu, reported by `on_click` for an item without a name.
+    #[prop_or_default]
+    pub index: usize,
+    /// A menu item can be link.
+    #[prop_or(false)]
+    pub link: bool,
+    /// Internal name of the MenuItem, reported by `on_click` and used as content when there is none.
+    #[prop_or_else(|| None)]
+    pub name: Option<String>,
+    /// Called on click with the name of the item, or its index if it has no name.
+    #[prop_or_default]
+    pub on_click: Callback<(MouseEvent, Either<usize, String>)>,
+    /// A menu item can take left or right position.
+    #[prop_or_else(|| None)]
+    pub position: Option<MenuPosition>,
+}
+
+impl Component for MenuItem {
+    type Message = MouseEvent;
+    type Properties = MenuItemProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { link, props, classes }
+    }
+
+    fn update(&mut self, event: Self::Message) -> ShouldRender {
+        if self.props.disabled {
+            event.prevent_default();
+        } else {
+            let key = match self.props.name {
+                Some(ref name) => Either::Right(name.clone()),
+                None => Either::Left(self.props.index),
+            };
+            self.props.on_click.emit((event, key));
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+              href=self.props.href.clone()
+              onclick=self.link.callback(|e| e)
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          <>
+                            { self.props.icon.clone() }
+                            {
+                                match self.props.content.as_ref().or(self.props.name.as_ref()) {
+                                    Some(content) => html! { content.clone() },
+                                    None => html! {}
+                                }
+                            }
+                          </>
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl MenuItemProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            active,
+            class_name,
+            color,
+            content,
+            disabled,
+            fitted,
+            header,
+            icon,
+            link,
+            name,
+            position,
+            ..
+        } = self;
+
+        cx!(
+            use_option(color),
+            use_option(position),
+            use_key(*active, "active"),
+            use_key(*disabled, "disabled"),
+            use_key(!icon.is_empty() && content.is_none() && name.is_none(), "icon"),
+            use_key(*header, "header"),
+            use_key(*link, "link"),
+            use_key_or_option_and_key(fitted, "fitted"),
+            use_str("item"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<MenuItemFitted> for &'static str {
+    fn from(m: MenuItemFitted) -> Self {
+        use MenuItemFitted::*;
+
+        match m {
+            Horizontally => "horizontally",
+            Vertically => "vertically",
+        }
+    }
+}
+
+impl AsRef<str> for MenuItemFitted {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
@@ -0,0 +1,89 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use super::MenuPosition;
+
+/// A menu can contain a sub menu.
+pub struct MenuMenu {
+    props: MenuMenuProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct MenuMenuProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A sub menu can take left or right position.
+    #[prop_or_else(|| None)]
+    pub position: Option<MenuPosition>,
+}
+
+impl Component for MenuMenu {
+    type Message = ();
+    type Properties = MenuMenuProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl MenuMenuProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            position,
+            ..
+        } = self;
+
+        cx!(
+            use_option(position),
+            use_str("menu"),
+            use_option(class_name)
+        )
+    }
+}
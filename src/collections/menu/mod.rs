@@ -0,0 +1,9 @@
+mod menu;
+mod menu_header;
+mod menu_item;
+mod menu_menu;
+
+pub use menu::*;
+pub use menu_header::*;
+pub use menu_item::*;
+pub use menu_menu::*;
\ No newline at end of file
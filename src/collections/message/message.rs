@@ -0,0 +1,236 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use crate::collections::Icon;
+use super::{MessageContent, MessageHeader, MessageList};
+
+/// A message displays information that explains nearby content.
+/// Also see [`MessageContent`](crate::collections::MessageContent), [`MessageHeader`](crate::collections::MessageHeader), [`MessageList`](crate::collections::MessageList)
+pub struct Message {
+    link: ComponentLink<Self>,
+    props: MessageProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MessageAttachedPosition {
+    Bottom,
+    Top,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct MessageProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// A message can be formatted to attach itself to other content.
+    #[prop_or_else(|| Either::Left(false))]
+    pub attached: Either<bool, MessageAttachedPosition>,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A message can be formatted to be different colors.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// A message can only take up the width of its content.
+    #[prop_or(false)]
+    pub compact: bool,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A message may be formatted to display a negative message. Same as `negative`.
+    #[prop_or(false)]
+    pub error: bool,
+    /// A message can float above content that it is related to.
+    #[prop_or(false)]
+    pub floating: bool,
+    /// Shorthand for MessageHeader.
+    #[prop_or_else(|| None)]
+    pub header: Option<String>,
+    /// A message can be hidden.
+    #[prop_or(false)]
+    pub hidden: bool,
+    /// A message can contain an icon by pass an <Icon />.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<Icon>,
+    /// A message may be formatted to display information.
+    #[prop_or(false)]
+    pub info: bool,
+    /// Array shorthand items for the MessageList.
+    #[prop_or_default]
+    pub list: Vec<String>,
+    /// A message may be formatted to display a negative message. Same as `error`.
+    #[prop_or(false)]
+    pub negative: bool,
+    /// Adds a close icon, called when the icon is clicked.
+    #[prop_or_else(|| None)]
+    pub on_dismiss: Option<Callback<MouseEvent>>,
+    /// A message may be formatted to display a positive message. Same as `success`.
+    #[prop_or(false)]
+    pub positive: bool,
+    /// A message can have different sizes.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// A message may be formatted to display a positive message. Same as `positive`.
+    #[prop_or(false)]
+    pub success: bool,
+    /// A message can be set to visible to force itself to be shown.
+    #[prop_or(false)]
+    pub visible: bool,
+    /// A message may be formatted to display warning messages.
+    #[prop_or(false)]
+    pub warning: bool,
+}
+
+impl Component for Message {
+    type Message = MouseEvent;
+    type Properties = MessageProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { link, props, classes }
+    }
+
+    fn update(&mut self, event: Self::Message) -> ShouldRender {
+        if let Some(cb) = self.props.on_dismiss.as_ref() {
+            cb.emit(event);
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let dismiss_icon = if self.props.on_dismiss.is_some() {
+            html! { <Icon name="close" onclick=self.link.callback(|e| e) /> }
+        } else {
+            html! {}
+        };
+
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                >
+                  { dismiss_icon }
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        let has_content = self.props.header.is_some()
+            || self.props.content.is_some()
+            || !self.props.list.is_empty();
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              { dismiss_icon }
+              { self.props.icon.clone() }
+              {
+                  if has_content {
+                      html! {
+                          <MessageContent>
+                            {
+                                match self.props.header {
+                                    Some(ref header) => html! { <MessageHeader content=header.clone() /> },
+                                    None => html! {}
+                                }
+                            }
+                            {
+                                if !self.props.list.is_empty() {
+                                    html! { <MessageList items=self.props.list.clone() /> }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                            {
+                                match self.props.content {
+                                    Some(ref content) => html! { <p>{ content.clone() }</p> },
+                                    None => html! {}
+                                }
+                            }
+                          </MessageContent>
+                      }
+                  } else {
+                      html! {}
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl MessageProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            attached,
+            class_name,
+            color,
+            compact,
+            error,
+            floating,
+            hidden,
+            icon,
+            info,
+            negative,
+            positive,
+            size,
+            success,
+            visible,
+            warning,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(color),
+            use_option(size),
+            use_key(*compact, "compact"),
+            use_key(*error, "error"),
+            use_key(*floating, "floating"),
+            use_key(*hidden, "hidden"),
+            use_key(!icon.is_empty(), "icon"),
+            use_key(*info, "info"),
+            use_key(*negative, "negative"),
+            use_key(*positive, "positive"),
+            use_key(*success, "success"),
+            use_key(*visible, "visible"),
+            use_key(*warning, "warning"),
+            use_key_or_option_and_key(attached, "attached"),
+            use_str("message"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<MessageAttachedPosition> for &'static str {
+    fn from(m: MessageAttachedPosition) -> Self {
+        use MessageAttachedPosition::*;
+
+        match m {
+            Bottom => "bottom",
+            Top => "top",
+        }
+    }
+}
+
+impl AsRef<str> for MessageAttachedPosition {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
@@ -0,0 +1,76 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use super::MessageItem;
+
+/// A message can contain a list of items.
+pub struct MessageList {
+    props: MessageListProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct MessageListProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "ul".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand array of MessageItem contents.
+    #[prop_or_default]
+    pub items: Vec<String>,
+}
+
+impl Component for MessageList {
+    type Message = ();
+    type Properties = MessageListProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! { for self.props.items.iter().map(|item| html! { <MessageItem content=item.clone() /> }) }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl MessageListProps {
+    fn derive_classes(&self) -> Vec<String> {
+        cx!(
+            use_str("list"),
+            use_option(&self.class_name)
+        )
+    }
+}
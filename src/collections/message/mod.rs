@@ -0,0 +1,11 @@
+mod message;
+mod message_content;
+mod message_header;
+mod message_item;
+mod message_list;
+
+pub use message::*;
+pub use message_content::*;
+pub use message_header::*;
+pub use message_item::*;
+pub use message_list::*;
\ No newline at end of file
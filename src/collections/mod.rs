@@ -1,8 +1,33 @@
+pub mod breadcrumb;
 pub mod container;
+pub mod data_table;
 pub mod button;
+pub mod card;
+pub mod comment;
+pub mod feed;
+pub mod form;
+pub mod grid;
+pub mod header;
 pub mod icon;
+pub mod image;
 pub mod label;
+pub mod menu;
+pub mod message;
+pub mod table;
 
+pub use breadcrumb::{Breadcrumb, BreadcrumbDivider, BreadcrumbSection};
+pub use button::{Button, ButtonContent, ButtonGroup, ButtonOr};
+pub use card::{Card, CardContent, CardDescription, CardGroup, CardHeader, CardMeta};
+pub use comment::{Comment, CommentActions, CommentAuthor, CommentAvatar, CommentContent, CommentGroup, CommentMetadata, CommentText};
 pub use container::Container;
+pub use data_table::{DataTable, DataTableColumn};
+pub use feed::{Feed, FeedContent, FeedDate, FeedEvent, FeedExtra, FeedLabel, FeedLike, FeedMeta, FeedSummary, FeedUser};
+pub use form::{Form, FormField, FormGroup};
+pub use grid::{Grid, GridColumn, GridRow};
+pub use header::{Header, HeaderContent, HeaderSubheader};
 pub use icon::{Icon, IconGroup};
-pub use label::{Label, LabelDetail};
+pub use image::{Image, ImageGroup};
+pub use label::{Label, LabelDetail, LabelGroup};
+pub use menu::{Menu, MenuHeader, MenuItem, MenuMenu};
+pub use message::{Message, MessageContent, MessageHeader, MessageItem, MessageList};
+pub use table::{Table, TableBody, TableCell, TableFooter, TableHeader, TableHeaderCell, TableRow};
\ No newline at end of file
@@ -0,0 +1,15 @@
+mod table;
+mod table_body;
+mod table_cell;
+mod table_footer;
+mod table_header;
+mod table_header_cell;
+mod table_row;
+
+pub use table::*;
+pub use table_body::*;
+pub use table_cell::*;
+pub use table_footer::*;
+pub use table_header::*;
+pub use table_header_cell::*;
+pub use table_row::*;
\ No newline at end of file
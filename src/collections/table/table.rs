@@ -0,0 +1,367 @@
+use std::fmt;
+use std::rc::Rc;
+
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::{TableBody, TableCellProps, TableHeader, TableHeaderCell, TableRow};
+
+/// A table displays a collection of data grouped into rows.
+/// Also see [`TableHeader`](crate::collections::TableHeader), [`TableBody`](crate::collections::TableBody),
+/// [`TableFooter`](crate::collections::TableFooter), [`TableRow`](crate::collections::TableRow),
+/// [`TableHeaderCell`](crate::collections::TableHeaderCell), [`TableCell`](crate::collections::TableCell)
+pub struct Table<T: Clone + PartialEq + 'static = ()> {
+    props: TableProps<T>,
+    classes: Vec<String>,
+}
+
+/// Renders a body row of a table from an item of `table_data` and its index.
+pub struct TableRenderBodyRow<T>(Rc<RenderBodyRowFn<T>>);
+
+type RenderBodyRowFn<T> = dyn Fn(&T, usize) -> Html;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TableAttachedPosition {
+    Top,
+    Bottom,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TableBasic {
+    Very,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TableCompact {
+    Very,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TablePadded {
+    Very,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct TableProps<T: Clone + PartialEq + 'static> {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "table".to_string())]
+    pub root: String,
+    /// A table can be attached to other content.
+    #[prop_or_else(|| Either::Left(false))]
+    pub attached: Either<bool, TableAttachedPosition>,
+    /// A table can reduce its complexity to increase readability.
+    #[prop_or_else(|| Either::Left(false))]
+    pub basic: Either<bool, TableBasic>,
+    /// A table may be divided each row into separate cells.
+    #[prop_or(false)]
+    pub celled: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A table can be collapsing, taking up only as much space as its rows.
+    #[prop_or(false)]
+    pub collapsing: bool,
+    /// A table can be given a color to distinguish it from other tables.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// A table can specify its column count to divide its content evenly.
+    #[prop_or_else(|| None)]
+    pub columns: Option<sui::Widths>,
+    /// A table may sometimes need to be more compact to make more rows visible at a time.
+    #[prop_or_else(|| Either::Left(false))]
+    pub compact: Either<bool, TableCompact>,
+    /// A table may be formatted to emphasize a first column that defines a rows content.
+    #[prop_or(false)]
+    pub definition: bool,
+    /// A table can use a special faster form of table rendering that does not resize table cells based on content.
+    #[prop_or(false)]
+    pub fixed: bool,
+    /// Shorthand array of props for the TableHeaderCell of the header row.
+    #[prop_or_default]
+    pub header_row: Vec<TableCellProps>,
+    /// A table's colors can be inverted.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// A table may sometimes need to be more padded for legibility.
+    #[prop_or_else(|| Either::Left(false))]
+    pub padded: Either<bool, TablePadded>,
+    /// Renders a body row for each item of `table_data`.
+    #[prop_or_else(|| None)]
+    pub render_body_row: Option<TableRenderBodyRow<T>>,
+    /// A table can have its rows appear selectable.
+    #[prop_or(false)]
+    pub selectable: bool,
+    /// A table can specify that its cell contents should remain on a single line and not wrap.
+    #[prop_or(false)]
+    pub single_line: bool,
+    /// A table can also be small or large.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// A table may allow a user to sort contents by clicking on a table header.
+    #[prop_or(false)]
+    pub sortable: bool,
+    /// A table can specify how it stacks table content responsively.
+    #[prop_or(false)]
+    pub stackable: bool,
+    /// A table can stripe alternate rows of content with a darker color to increase contrast.
+    #[prop_or(false)]
+    pub striped: bool,
+    /// A table can be formatted to display complex structured data.
+    #[prop_or(false)]
+    pub structured: bool,
+    /// Data to be passed to the `render_body_row` function.
+    #[prop_or_default]
+    pub table_data: Vec<T>,
+    /// A table can adjust its text alignment.
+    #[prop_or_else(|| None)]
+    pub text_align: Option<sui::TextAlign>,
+    /// A table can opt out of stacking its content on mobile resolutions.
+    #[prop_or(false)]
+    pub unstackable: bool,
+    /// A table can adjust its vertical alignment.
+    #[prop_or_else(|| None)]
+    pub vertical_align: Option<sui::VerticalAlign>,
+}
+
+impl<T: Clone + PartialEq + 'static> Component for Table<T> {
+    type Message = ();
+    type Properties = TableProps<T>;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          <>
+                            { self.render_header_row() }
+                            { self.render_body() }
+                          </>
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Table<T> {
+    fn render_header_row(&self) -> Html {
+        if self.props.header_row.is_empty() {
+            return html! {}
+        }
+
+        html! {
+            <TableHeader>
+              <TableRow>
+                {
+                    for self.props.header_row.iter().map(|cell| {
+                        let props = cell.clone();
+                        html! { <TableHeaderCell with props /> }
+                    })
+                }
+              </TableRow>
+            </TableHeader>
+        }
+    }
+
+    fn render_body(&self) -> Html {
+        match self.props.render_body_row {
+            Some(ref render) => html! {
+                <TableBody>
+                  {
+                      for self.props.table_data
+                          .iter()
+                          .enumerate()
+                          .map(|(index, data)| render.render(data, index))
+                  }
+                </TableBody>
+            },
+            None => html! {}
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> TableProps<T> {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            attached,
+            basic,
+            celled,
+            class_name,
+            collapsing,
+            color,
+            columns,
+            compact,
+            definition,
+            fixed,
+            inverted,
+            padded,
+            selectable,
+            single_line,
+            size,
+            sortable,
+            stackable,
+            striped,
+            structured,
+            text_align,
+            unstackable,
+            vertical_align,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(color),
+            use_option(size),
+            use_key(*celled, "celled"),
+            use_key(*collapsing, "collapsing"),
+            use_key(*definition, "definition"),
+            use_key(*fixed, "fixed"),
+            use_key(*inverted, "inverted"),
+            use_key(*selectable, "selectable"),
+            use_key(*single_line, "single line"),
+            use_key(*sortable, "sortable"),
+            use_key(*stackable, "stackable"),
+            use_key(*striped, "striped"),
+            use_key(*structured, "structured"),
+            use_key(*unstackable, "unstackable"),
+            use_key_or_option_and_key(attached, "attached"),
+            use_key_or_option_and_key(basic, "basic"),
+            use_key_or_option_and_key(compact, "compact"),
+            use_key_or_option_and_key(padded, "padded"),
+            use_option_text_align(text_align),
+            use_option_and_key(vertical_align, "aligned"),
+            use_option_and_key(columns, "column"),
+            use_str("table"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl<T> TableRenderBodyRow<T> {
+    pub fn new<F: Fn(&T, usize) -> Html + 'static>(render: F) -> Self {
+        Self(Rc::new(render))
+    }
+
+    pub fn render(&self, data: &T, index: usize) -> Html {
+        (self.0)(data, index)
+    }
+}
+
+impl<T, F: Fn(&T, usize) -> Html + 'static> From<F> for TableRenderBodyRow<T> {
+    fn from(render: F) -> Self {
+        Self::new(render)
+    }
+}
+
+impl<T> Clone for TableRenderBodyRow<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for TableRenderBodyRow<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::as_ptr(&self.0) as *const u8 == Rc::as_ptr(&other.0) as *const u8
+    }
+}
+
+impl<T> fmt::Debug for TableRenderBodyRow<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TableRenderBodyRow")
+    }
+}
+
+impl From<TableAttachedPosition> for &'static str {
+    fn from(t: TableAttachedPosition) -> Self {
+        use TableAttachedPosition::*;
+
+        match t {
+            Top => "top",
+            Bottom => "bottom",
+        }
+    }
+}
+
+impl AsRef<str> for TableAttachedPosition {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<TableBasic> for &'static str {
+    fn from(t: TableBasic) -> Self {
+        use TableBasic::*;
+
+        match t {
+            Very => "very",
+        }
+    }
+}
+
+impl AsRef<str> for TableBasic {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<TableCompact> for &'static str {
+    fn from(t: TableCompact) -> Self {
+        use TableCompact::*;
+
+        match t {
+            Very => "very",
+        }
+    }
+}
+
+impl AsRef<str> for TableCompact {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<TablePadded> for &'static str {
+    fn from(t: TablePadded) -> Self {
+        use TablePadded::*;
+
+        match t {
+            Very => "very",
+        }
+    }
+}
+
+impl AsRef<str> for TablePadded {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
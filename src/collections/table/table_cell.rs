@@ -0,0 +1,191 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use crate::collections::Icon;
+
+/// A table row can have cells.
+pub struct TableCell {
+    link: ComponentLink<Self>,
+    props: TableCellProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TableSorted {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct TableCellProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "td".to_string())]
+    pub root: String,
+    /// A cell can be active or selected by a user.
+    #[prop_or(false)]
+    pub active: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// The number of columns spanned by the cell.
+    #[prop_or_else(|| None)]
+    pub col_span: Option<usize>,
+    /// A cell can be collapsing so that it only uses as much space as required.
+    #[prop_or(false)]
+    pub collapsing: bool,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A cell can be disabled.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// A cell may call attention to an error or a negative value.
+    #[prop_or(false)]
+    pub error: bool,
+    /// Add an Icon by pass an <Icon />.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<Icon>,
+    /// A cell may let a user know whether a value is bad.
+    #[prop_or(false)]
+    pub negative: bool,
+    /// A cell may let a user know whether a value is good.
+    #[prop_or(false)]
+    pub positive: bool,
+    /// Called on click.
+    #[prop_or_default]
+    pub on_click: Callback<MouseEvent>,
+    /// A cell can be selectable.
+    #[prop_or(false)]
+    pub selectable: bool,
+    /// A cell can specify that its contents should remain on a single line and not wrap.
+    #[prop_or(false)]
+    pub single_line: bool,
+    /// A header cell can be sorted in ascending or descending order.
+    #[prop_or_else(|| None)]
+    pub sorted: Option<TableSorted>,
+    /// A table cell can adjust its text alignment.
+    #[prop_or_else(|| None)]
+    pub text_align: Option<sui::TextAlign>,
+    /// A table cell can adjust its vertical alignment.
+    #[prop_or_else(|| None)]
+    pub vertical_align: Option<sui::VerticalAlign>,
+    /// A cell may warn a user.
+    #[prop_or(false)]
+    pub warning: bool,
+    /// A table can specify the width of individual columns independently.
+    #[prop_or_else(|| None)]
+    pub width: Option<sui::Widths>,
+}
+
+impl Component for TableCell {
+    type Message = MouseEvent;
+    type Properties = TableCellProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { link, props, classes }
+    }
+
+    fn update(&mut self, event: Self::Message) -> ShouldRender {
+        self.props.on_click.emit(event);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+              colspan=self.props.col_span.map(|n| n.to_string())
+              onclick=self.link.callback(|e| e)
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          <>
+                            { self.props.icon.clone() }
+                            {
+                                match self.props.content {
+                                    Some(ref content) => html! { content.clone() },
+                                    None => html! {}
+                                }
+                            }
+                          </>
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl TableCellProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            active,
+            class_name,
+            collapsing,
+            disabled,
+            error,
+            negative,
+            positive,
+            selectable,
+            single_line,
+            sorted,
+            text_align,
+            vertical_align,
+            warning,
+            width,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*active, "active"),
+            use_key(*collapsing, "collapsing"),
+            use_key(*disabled, "disabled"),
+            use_key(*error, "error"),
+            use_key(*negative, "negative"),
+            use_key(*positive, "positive"),
+            use_key(*selectable, "selectable"),
+            use_key(*single_line, "single line"),
+            use_key(*warning, "warning"),
+            use_option_text_align(text_align),
+            use_option_and_key(vertical_align, "aligned"),
+            use_option_and_key(width, "wide"),
+            use_option_and_key(sorted, "sorted"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<TableSorted> for &'static str {
+    fn from(t: TableSorted) -> Self {
+        use TableSorted::*;
+
+        match t {
+            Ascending => "ascending",
+            Descending => "descending",
+        }
+    }
+}
+
+impl AsRef<str> for TableSorted {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
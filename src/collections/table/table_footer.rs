@@ -0,0 +1,75 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+
+/// A table can have a footer.
+pub struct TableFooter {
+    props: TableFooterProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct TableFooterProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "tfoot".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A definition table can have a full width footer or header, filling in the gap left by the first column.
+    #[prop_or(false)]
+    pub full_width: bool,
+}
+
+impl Component for TableFooter {
+    type Message = ();
+    type Properties = TableFooterProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              { self.props.children.clone() }
+            </@>
+        }
+    }
+}
+
+impl TableFooterProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            full_width,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*full_width, "full-width"),
+            use_option(class_name)
+        )
+    }
+}
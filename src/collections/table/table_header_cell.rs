@@ -0,0 +1,39 @@
+use yew::prelude::*;
+
+use super::{TableCell, TableCellProps};
+
+/// A table can have a header cell, rendered as a `th` [`TableCell`](crate::collections::TableCell)
+/// which can be `sorted`.
+pub struct TableHeaderCell {
+    props: TableCellProps,
+}
+
+impl Component for TableHeaderCell {
+    type Message = ();
+    type Properties = TableCellProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let mut props = self.props.clone();
+        if props.root == "td" {
+            props.root = "th".to_string();
+        }
+        html! { <TableCell with props /> }
+    }
+}
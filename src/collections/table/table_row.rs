@@ -0,0 +1,125 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::{TableCell, TableCellProps};
+
+/// A table can have rows.
+pub struct TableRow {
+    props: TableRowProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct TableRowProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "tr".to_string())]
+    pub root: String,
+    /// A row can be active or selected by a user.
+    #[prop_or(false)]
+    pub active: bool,
+    /// Shorthand array of props for TableCell.
+    #[prop_or_default]
+    pub cells: Vec<TableCellProps>,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A row can be disabled.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// A row may call attention to an error or a negative value.
+    #[prop_or(false)]
+    pub error: bool,
+    /// A row may let a user know whether a value is bad.
+    #[prop_or(false)]
+    pub negative: bool,
+    /// A row may let a user know whether a value is good.
+    #[prop_or(false)]
+    pub positive: bool,
+    /// A table row can adjust its text alignment.
+    #[prop_or_else(|| None)]
+    pub text_align: Option<sui::TextAlign>,
+    /// A table row can adjust its vertical alignment.
+    #[prop_or_else(|| None)]
+    pub vertical_align: Option<sui::VerticalAlign>,
+    /// A row may warn a user.
+    #[prop_or(false)]
+    pub warning: bool,
+}
+
+impl Component for TableRow {
+    type Message = ();
+    type Properties = TableRowProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      html! {
+                          for self.props.cells.iter().map(|cell| {
+                              let props = cell.clone();
+                              html! { <TableCell with props /> }
+                          })
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl TableRowProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            active,
+            class_name,
+            disabled,
+            error,
+            negative,
+            positive,
+            text_align,
+            vertical_align,
+            warning,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*active, "active"),
+            use_key(*disabled, "disabled"),
+            use_key(*error, "error"),
+            use_key(*negative, "negative"),
+            use_key(*positive, "positive"),
+            use_key(*warning, "warning"),
+            use_option_text_align(text_align),
+            use_option_and_key(vertical_align, "aligned"),
+            use_option(class_name)
+        )
+    }
+}
@@ -0,0 +1,119 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+
+/// A divider visually segments content into groups.
+pub struct Divider {
+    props: DividerProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct DividerProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Divider can clear the content above it.
+    #[prop_or(false)]
+    pub clearing: bool,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Divider can be fitted without any space above or below it.
+    #[prop_or(false)]
+    pub fitted: bool,
+    /// Divider can divide content without creating a dividing line.
+    #[prop_or(false)]
+    pub hidden: bool,
+    /// Divider can segment content horizontally.
+    #[prop_or(false)]
+    pub horizontal: bool,
+    /// Divider can have its colors inverted.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// Divider can provide greater margins to divide sections of content.
+    #[prop_or(false)]
+    pub section: bool,
+    /// Divider can segment content vertically.
+    #[prop_or(false)]
+    pub vertical: bool,
+}
+
+impl Component for Divider {
+    type Message = ();
+    type Properties = DividerProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl DividerProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            clearing,
+            fitted,
+            hidden,
+            horizontal,
+            inverted,
+            section,
+            vertical,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_key(*clearing, "clearing"),
+            use_key(*fitted, "fitted"),
+            use_key(*hidden, "hidden"),
+            use_key(*horizontal, "horizontal"),
+            use_key(*inverted, "inverted"),
+            use_key(*section, "section"),
+            use_key(*vertical, "vertical"),
+            use_str("divider"),
+            use_option(class_name)
+        )
+    }
+}
@@ -0,0 +1,3 @@
+mod divider;
+
+pub use divider::*;
\ No newline at end of file
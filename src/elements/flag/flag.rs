@@ -0,0 +1,71 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use super::FlagName;
+
+/// A flag is used to represent a political state.
+pub struct Flag {
+    props: FlagProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FlagProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "i".to_string())]
+    pub root: String,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Flag name, can use the two letter country code, the full name, or a common alias.
+    pub name: FlagName,
+}
+
+impl Component for Flag {
+    type Message = ();
+    type Properties = FlagProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            />
+        }
+    }
+}
+
+impl FlagProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            name,
+            ..
+        } = self;
+
+        cx!(
+            use_str(name),
+            use_str("flag"),
+            use_option(class_name)
+        )
+    }
+}
@@ -0,0 +1,963 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Names of the country flags supported by Semantic UI.
+///
+/// A name is rendered as its ISO-3166 code, and can be parsed from
+/// either the code or one of its aliases, e.g. `"us"`, `"united states"` or `"america"`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FlagName {
+    Andorra,
+    UnitedArabEmirates,
+    Afghanistan,
+    Antigua,
+    Anguilla,
+    Albania,
+    Armenia,
+    NetherlandsAntilles,
+    Angola,
+    Argentina,
+    AmericanSamoa,
+    Austria,
+    Australia,
+    Aruba,
+    AlandIslands,
+    Azerbaijan,
+    Bosnia,
+    Barbados,
+    Bangladesh,
+    Belgium,
+    BurkinaFaso,
+    Bulgaria,
+    Bahrain,
+    Burundi,
+    Benin,
+    Bermuda,
+    Brunei,
+    Bolivia,
+    Brazil,
+    Bahamas,
+    Bhutan,
+    BouvetIsland,
+    Botswana,
+    Belarus,
+    Belize,
+    Canada,
+    CocosIslands,
+    Congo,
+    CentralAfricanRepublic,
+    CongoBrazzaville,
+    Switzerland,
+    CoteDivoire,
+    CookIslands,
+    Chile,
+    Cameroon,
+    China,
+    Colombia,
+    CostaRica,
+    SerbiaAndMontenegro,
+    Cuba,
+    CapeVerde,
+    ChristmasIsland,
+    Cyprus,
+    CzechRepublic,
+    Germany,
+    Djibouti,
+    Denmark,
+    Dominica,
+    DominicanRepublic,
+    Algeria,
+    Ecuador,
+    Estonia,
+    Egypt,
+    WesternSahara,
+    England,
+    Eritrea,
+    Spain,
+    Ethiopia,
+    EuropeanUnion,
+    Finland,
+    Fiji,
+    FalklandIslands,
+    Micronesia,
+    FaroeIslands,
+    France,
+    Gabon,
+    UnitedKingdom,
+    Grenada,
+    Georgia,
+    FrenchGuiana,
+    Ghana,
+    Gibraltar,
+    Greenland,
+    Gambia,
+    Guinea,
+    Guadeloupe,
+    EquatorialGuinea,
+    Greece,
+    SandwichIslands,
+    Guatemala,
+    Guam,
+    GuineaBissau,
+    Guyana,
+    HongKong,
+    HeardIsland,
+    Honduras,
+    Croatia,
+    Haiti,
+    Hungary,
+    Indonesia,
+    Ireland,
+    Israel,
+    India,
+    IndianOceanTerritory,
+    Iraq,
+    Iran,
+    Iceland,
+    Italy,
+    Jamaica,
+    Jordan,
+    Japan,
+    Kenya,
+    Kyrgyzstan,
+    Cambodia,
+    Kiribati,
+    Comoros,
+    SaintKittsAndNevis,
+    NorthKorea,
+    SouthKorea,
+    Kuwait,
+    CaymanIslands,
+    Kazakhstan,
+    Laos,
+    Lebanon,
+    SaintLucia,
+    Liechtenstein,
+    SriLanka,
+    Liberia,
+    Lesotho,
+    Lithuania,
+    Luxembourg,
+    Latvia,
+    Libya,
+    Morocco,
+    Monaco,
+    Moldova,
+    Montenegro,
+    Madagascar,
+    MarshallIslands,
+    Macedonia,
+    Mali,
+    Myanmar,
+    Mongolia,
+    Macau,
+    NorthernMarianaIslands,
+    Martinique,
+    Mauritania,
+    Montserrat,
+    Malta,
+    Mauritius,
+    Maldives,
+    Malawi,
+    Mexico,
+    Malaysia,
+    Mozambique,
+    Namibia,
+    NewCaledonia,
+    Niger,
+    NorfolkIsland,
+    Nigeria,
+    Nicaragua,
+    Netherlands,
+    Norway,
+    Nepal,
+    Nauru,
+    Niue,
+    NewZealand,
+    Oman,
+    Panama,
+    Peru,
+    FrenchPolynesia,
+    NewGuinea,
+    Philippines,
+    Pakistan,
+    Poland,
+    SaintPierre,
+    PitcairnIslands,
+    PuertoRico,
+    Palestine,
+    Portugal,
+    Palau,
+    Paraguay,
+    Qatar,
+    Reunion,
+    Romania,
+    Serbia,
+    Russia,
+    Rwanda,
+    SaudiArabia,
+    SolomonIslands,
+    Seychelles,
+    Scotland,
+    Sudan,
+    Sweden,
+    Singapore,
+    SaintHelena,
+    Slovenia,
+    Svalbard,
+    Slovakia,
+    SierraLeone,
+    SanMarino,
+    Senegal,
+    Somalia,
+    Suriname,
+    SaoTome,
+    ElSalvador,
+    Syria,
+    Swaziland,
+    CaicosIslands,
+    Chad,
+    FrenchTerritories,
+    Togo,
+    Thailand,
+    Tajikistan,
+    Tokelau,
+    Timorleste,
+    Turkmenistan,
+    Tunisia,
+    Tonga,
+    Turkey,
+    Trinidad,
+    Tuvalu,
+    Taiwan,
+    Tanzania,
+    Ukraine,
+    Uganda,
+    UsMinorIslands,
+    UnitedStates,
+    Uruguay,
+    Uzbekistan,
+    VaticanCity,
+    SaintVincent,
+    Venezuela,
+    BritishVirginIslands,
+    UsVirginIslands,
+    Vietnam,
+    Vanuatu,
+    Wales,
+    WallisAndFutuna,
+    Samoa,
+    Yemen,
+    Mayotte,
+    SouthAfrica,
+    Zambia,
+    Zimbabwe,
+}
+
+/// The error returned when a string is not a known [`FlagName`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseFlagNameError(String);
+
+impl From<FlagName> for &'static str {
+    fn from(f: FlagName) -> Self {
+        use FlagName::*;
+
+        match f {
+            Andorra => "ad",
+            UnitedArabEmirates => "ae",
+            Afghanistan => "af",
+            Antigua => "ag",
+            Anguilla => "ai",
+            Albania => "al",
+            Armenia => "am",
+            NetherlandsAntilles => "an",
+            Angola => "ao",
+            Argentina => "ar",
+            AmericanSamoa => "as",
+            Austria => "at",
+            Australia => "au",
+            Aruba => "aw",
+            AlandIslands => "ax",
+            Azerbaijan => "az",
+            Bosnia => "ba",
+            Barbados => "bb",
+            Bangladesh => "bd",
+            Belgium => "be",
+            BurkinaFaso => "bf",
+            Bulgaria => "bg",
+            Bahrain => "bh",
+            Burundi => "bi",
+            Benin => "bj",
+            Bermuda => "bm",
+            Brunei => "bn",
+            Bolivia => "bo",
+            Brazil => "br",
+            Bahamas => "bs",
+            Bhutan => "bt",
+            BouvetIsland => "bv",
+            Botswana => "bw",
+            Belarus => "by",
+            Belize => "bz",
+            Canada => "ca",
+            CocosIslands => "cc",
+            Congo => "cd",
+            CentralAfricanRepublic => "cf",
+            CongoBrazzaville => "cg",
+            Switzerland => "ch",
+            CoteDivoire => "ci",
+            CookIslands => "ck",
+            Chile => "cl",
+            Cameroon => "cm",
+            China => "cn",
+            Colombia => "co",
+            CostaRica => "cr",
+            SerbiaAndMontenegro => "cs",
+            Cuba => "cu",
+            CapeVerde => "cv",
+            ChristmasIsland => "cx",
+            Cyprus => "cy",
+            CzechRepublic => "cz",
+            Germany => "de",
+            Djibouti => "dj",
+            Denmark => "dk",
+            Dominica => "dm",
+            DominicanRepublic => "do",
+            Algeria => "dz",
+            Ecuador => "ec",
+            Estonia => "ee",
+            Egypt => "eg",
+            WesternSahara => "eh",
+            England => "gb eng",
+            Eritrea => "er",
+            Spain => "es",
+            Ethiopia => "et",
+            EuropeanUnion => "eu",
+            Finland => "fi",
+            Fiji => "fj",
+            FalklandIslands => "fk",
+            Micronesia => "fm",
+            FaroeIslands => "fo",
+            France => "fr",
+            Gabon => "ga",
+            UnitedKingdom => "gb",
+            Grenada => "gd",
+            Georgia => "ge",
+            FrenchGuiana => "gf",
+            Ghana => "gh",
+            Gibraltar => "gi",
+            Greenland => "gl",
+            Gambia => "gm",
+            Guinea => "gn",
+            Guadeloupe => "gp",
+            EquatorialGuinea => "gq",
+            Greece => "gr",
+            SandwichIslands => "gs",
+            Guatemala => "gt",
+            Guam => "gu",
+            GuineaBissau => "gw",
+            Guyana => "gy",
+            HongKong => "hk",
+            HeardIsland => "hm",
+            Honduras => "hn",
+            Croatia => "hr",
+            Haiti => "ht",
+            Hungary => "hu",
+            Indonesia => "id",
+            Ireland => "ie",
+            Israel => "il",
+            India => "in",
+            IndianOceanTerritory => "io",
+            Iraq => "iq",
+            Iran => "ir",
+            Iceland => "is",
+            Italy => "it",
+            Jamaica => "jm",
+            Jordan => "jo",
+            Japan => "jp",
+            Kenya => "ke",
+            Kyrgyzstan => "kg",
+            Cambodia => "kh",
+            Kiribati => "ki",
+            Comoros => "km",
+            SaintKittsAndNevis => "kn",
+            NorthKorea => "kp",
+            SouthKorea => "kr",
+            Kuwait => "kw",
+            CaymanIslands => "ky",
+            Kazakhstan => "kz",
+            Laos => "la",
+            Lebanon => "lb",
+            SaintLucia => "lc",
+            Liechtenstein => "li",
+            SriLanka => "lk",
+            Liberia => "lr",
+            Lesotho => "ls",
+            Lithuania => "lt",
+            Luxembourg => "lu",
+            Latvia => "lv",
+            Libya => "ly",
+            Morocco => "ma",
+            Monaco => "mc",
+            Moldova => "md",
+            Montenegro => "me",
+            Madagascar => "mg",
+            MarshallIslands => "mh",
+            Macedonia => "mk",
+            Mali => "ml",
+            Myanmar => "mm",
+            Mongolia => "mn",
+            Macau => "mo",
+            NorthernMarianaIslands => "mp",
+            Martinique => "mq",
+            Mauritania => "mr",
+            Montserrat => "ms",
+            Malta => "mt",
+            Mauritius => "mu",
+            Maldives => "mv",
+            Malawi => "mw",
+            Mexico => "mx",
+            Malaysia => "my",
+            Mozambique => "mz",
+            Namibia => "na",
+            NewCaledonia => "nc",
+            Niger => "ne",
+            NorfolkIsland => "nf",
+            Nigeria => "ng",
+            Nicaragua => "ni",
+            Netherlands => "nl",
+            Norway => "no",
+            Nepal => "np",
+            Nauru => "nr",
+            Niue => "nu",
+            NewZealand => "nz",
+            Oman => "om",
+            Panama => "pa",
+            Peru => "pe",
+            FrenchPolynesia => "pf",
+            NewGuinea => "pg",
+            Philippines => "ph",
+            Pakistan => "pk",
+            Poland => "pl",
+            SaintPierre => "pm",
+            PitcairnIslands => "pn",
+            PuertoRico => "pr",
+            Palestine => "ps",
+            Portugal => "pt",
+            Palau => "pw",
+            Paraguay => "py",
+            Qatar => "qa",
+            Reunion => "re",
+            Romania => "ro",
+            Serbia => "rs",
+            Russia => "ru",
+            Rwanda => "rw",
+            SaudiArabia => "sa",
+            SolomonIslands => "sb",
+            Seychelles => "sc",
+            Scotland => "gb sct",
+            Sudan => "sd",
+            Sweden => "se",
+            Singapore => "sg",
+            SaintHelena => "sh",
+            Slovenia => "si",
+            Svalbard => "sj",
+            Slovakia => "sk",
+            SierraLeone => "sl",
+            SanMarino => "sm",
+            Senegal => "sn",
+            Somalia => "so",
+            Suriname => "sr",
+            SaoTome => "st",
+            ElSalvador => "sv",
+            Syria => "sy",
+            Swaziland => "sz",
+            CaicosIslands => "tc",
+            Chad => "td",
+            FrenchTerritories => "tf",
+            Togo => "tg",
+            Thailand => "th",
+            Tajikistan => "tj",
+            Tokelau => "tk",
+            Timorleste => "tl",
+            Turkmenistan => "tm",
+            Tunisia => "tn",
+            Tonga => "to",
+            Turkey => "tr",
+            Trinidad => "tt",
+            Tuvalu => "tv",
+            Taiwan => "tw",
+            Tanzania => "tz",
+            Ukraine => "ua",
+            Uganda => "ug",
+            UsMinorIslands => "um",
+            UnitedStates => "us",
+            Uruguay => "uy",
+            Uzbekistan => "uz",
+            VaticanCity => "va",
+            SaintVincent => "vc",
+            Venezuela => "ve",
+            BritishVirginIslands => "vg",
+            UsVirginIslands => "vi",
+            Vietnam => "vn",
+            Vanuatu => "vu",
+            Wales => "gb wls",
+            WallisAndFutuna => "wf",
+            Samoa => "ws",
+            Yemen => "ye",
+            Mayotte => "yt",
+            SouthAfrica => "za",
+            Zambia => "zm",
+            Zimbabwe => "zw",
+        }
+    }
+}
+
+impl AsRef<str> for FlagName {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl fmt::Display for FlagName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl FromStr for FlagName {
+    type Err = ParseFlagNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use FlagName::*;
+
+        match s.trim().to_lowercase().as_str() {
+            "ad" | "andorra" => Ok(Andorra),
+            "ae" | "united arab emirates" | "uae" => Ok(UnitedArabEmirates),
+            "af" | "afghanistan" => Ok(Afghanistan),
+            "ag" | "antigua" => Ok(Antigua),
+            "ai" | "anguilla" => Ok(Anguilla),
+            "al" | "albania" => Ok(Albania),
+            "am" | "armenia" => Ok(Armenia),
+            "an" | "netherlands antilles" => Ok(NetherlandsAntilles),
+            "ao" | "angola" => Ok(Angola),
+            "ar" | "argentina" => Ok(Argentina),
+            "as" | "american samoa" => Ok(AmericanSamoa),
+            "at" | "austria" => Ok(Austria),
+            "au" | "australia" => Ok(Australia),
+            "aw" | "aruba" => Ok(Aruba),
+            "ax" | "aland islands" => Ok(AlandIslands),
+            "az" | "azerbaijan" => Ok(Azerbaijan),
+            "ba" | "bosnia" => Ok(Bosnia),
+            "bb" | "barbados" => Ok(Barbados),
+            "bd" | "bangladesh" => Ok(Bangladesh),
+            "be" | "belgium" => Ok(Belgium),
+            "bf" | "burkina faso" => Ok(BurkinaFaso),
+            "bg" | "bulgaria" => Ok(Bulgaria),
+            "bh" | "bahrain" => Ok(Bahrain),
+            "bi" | "burundi" => Ok(Burundi),
+            "bj" | "benin" => Ok(Benin),
+            "bm" | "bermuda" => Ok(Bermuda),
+            "bn" | "brunei" => Ok(Brunei),
+            "bo" | "bolivia" => Ok(Bolivia),
+            "br" | "brazil" => Ok(Brazil),
+            "bs" | "bahamas" => Ok(Bahamas),
+            "bt" | "bhutan" => Ok(Bhutan),
+            "bv" | "bouvet island" => Ok(BouvetIsland),
+            "bw" | "botswana" => Ok(Botswana),
+            "by" | "belarus" => Ok(Belarus),
+            "bz" | "belize" => Ok(Belize),
+            "ca" | "canada" => Ok(Canada),
+            "cc" | "cocos islands" => Ok(CocosIslands),
+            "cd" | "congo" => Ok(Congo),
+            "cf" | "central african republic" => Ok(CentralAfricanRepublic),
+            "cg" | "congo brazzaville" => Ok(CongoBrazzaville),
+            "ch" | "switzerland" => Ok(Switzerland),
+            "ci" | "cote divoire" => Ok(CoteDivoire),
+            "ck" | "cook islands" => Ok(CookIslands),
+            "cl" | "chile" => Ok(Chile),
+            "cm" | "cameroon" => Ok(Cameroon),
+            "cn" | "china" => Ok(China),
+            "co" | "colombia" => Ok(Colombia),
+            "cr" | "costa rica" => Ok(CostaRica),
+            "cs" | "serbia and montenegro" => Ok(SerbiaAndMontenegro),
+            "cu" | "cuba" => Ok(Cuba),
+            "cv" | "cape verde" => Ok(CapeVerde),
+            "cx" | "christmas island" => Ok(ChristmasIsland),
+            "cy" | "cyprus" => Ok(Cyprus),
+            "cz" | "czech republic" => Ok(CzechRepublic),
+            "de" | "germany" => Ok(Germany),
+            "dj" | "djibouti" => Ok(Djibouti),
+            "dk" | "denmark" => Ok(Denmark),
+            "dm" | "dominica" => Ok(Dominica),
+            "do" | "dominican republic" => Ok(DominicanRepublic),
+            "dz" | "algeria" => Ok(Algeria),
+            "ec" | "ecuador" => Ok(Ecuador),
+            "ee" | "estonia" => Ok(Estonia),
+            "eg" | "egypt" => Ok(Egypt),
+            "eh" | "western sahara" => Ok(WesternSahara),
+            "gb eng" | "england" => Ok(England),
+            "er" | "eritrea" => Ok(Eritrea),
+            "es" | "spain" => Ok(Spain),
+            "et" | "ethiopia" => Ok(Ethiopia),
+            "eu" | "european union" => Ok(EuropeanUnion),
+            "fi" | "finland" => Ok(Finland),
+            "fj" | "fiji" => Ok(Fiji),
+            "fk" | "falkland islands" => Ok(FalklandIslands),
+            "fm" | "micronesia" => Ok(Micronesia),
+            "fo" | "faroe islands" => Ok(FaroeIslands),
+            "fr" | "france" => Ok(France),
+            "ga" | "gabon" => Ok(Gabon),
+            "gb" | "uk" | "united kingdom" => Ok(UnitedKingdom),
+            "gd" | "grenada" => Ok(Grenada),
+            "ge" | "georgia" => Ok(Georgia),
+            "gf" | "french guiana" => Ok(FrenchGuiana),
+            "gh" | "ghana" => Ok(Ghana),
+            "gi" | "gibraltar" => Ok(Gibraltar),
+            "gl" | "greenland" => Ok(Greenland),
+            "gm" | "gambia" => Ok(Gambia),
+            "gn" | "guinea" => Ok(Guinea),
+            "gp" | "guadeloupe" => Ok(Guadeloupe),
+            "gq" | "equatorial guinea" => Ok(EquatorialGuinea),
+            "gr" | "greece" => Ok(Greece),
+            "gs" | "sandwich islands" => Ok(SandwichIslands),
+            "gt" | "guatemala" => Ok(Guatemala),
+            "gu" | "guam" => Ok(Guam),
+            "gw" | "guinea-bissau" => Ok(GuineaBissau),
+            "gy" | "guyana" => Ok(Guyana),
+            "hk" | "hong kong" => Ok(HongKong),
+            "hm" | "heard island" => Ok(HeardIsland),
+            "hn" | "honduras" => Ok(Honduras),
+            "hr" | "croatia" => Ok(Croatia),
+            "ht" | "haiti" => Ok(Haiti),
+            "hu" | "hungary" => Ok(Hungary),
+            "id" | "indonesia" => Ok(Indonesia),
+            "ie" | "ireland" => Ok(Ireland),
+            "il" | "israel" => Ok(Israel),
+            "in" | "india" => Ok(India),
+            "io" | "indian ocean territory" => Ok(IndianOceanTerritory),
+            "iq" | "iraq" => Ok(Iraq),
+            "ir" | "iran" => Ok(Iran),
+            "is" | "iceland" => Ok(Iceland),
+            "it" | "italy" => Ok(Italy),
+            "jm" | "jamaica" => Ok(Jamaica),
+            "jo" | "jordan" => Ok(Jordan),
+            "jp" | "japan" => Ok(Japan),
+            "ke" | "kenya" => Ok(Kenya),
+            "kg" | "kyrgyzstan" => Ok(Kyrgyzstan),
+            "kh" | "cambodia" => Ok(Cambodia),
+            "ki" | "kiribati" => Ok(Kiribati),
+            "km" | "comoros" => Ok(Comoros),
+            "kn" | "saint kitts and nevis" => Ok(SaintKittsAndNevis),
+            "kp" | "north korea" => Ok(NorthKorea),
+            "kr" | "south korea" => Ok(SouthKorea),
+            "kw" | "kuwait" => Ok(Kuwait),
+            "ky" | "cayman islands" => Ok(CaymanIslands),
+            "kz" | "kazakhstan" => Ok(Kazakhstan),
+            "la" | "laos" => Ok(Laos),
+            "lb" | "lebanon" => Ok(Lebanon),
+            "lc" | "saint lucia" => Ok(SaintLucia),
+            "li" | "liechtenstein" => Ok(Liechtenstein),
+            "lk" | "sri lanka" => Ok(SriLanka),
+            "lr" | "liberia" => Ok(Liberia),
+            "ls" | "lesotho" => Ok(Lesotho),
+            "lt" | "lithuania" => Ok(Lithuania),
+            "lu" | "luxembourg" => Ok(Luxembourg),
+            "lv" | "latvia" => Ok(Latvia),
+            "ly" | "libya" => Ok(Libya),
+            "ma" | "morocco" => Ok(Morocco),
+            "mc" | "monaco" => Ok(Monaco),
+            "md" | "moldova" => Ok(Moldova),
+            "me" | "montenegro" => Ok(Montenegro),
+            "mg" | "madagascar" => Ok(Madagascar),
+            "mh" | "marshall islands" => Ok(MarshallIslands),
+            "mk" | "macedonia" => Ok(Macedonia),
+            "ml" | "mali" => Ok(Mali),
+            "mm" | "myanmar" | "burma" => Ok(Myanmar),
+            "mn" | "mongolia" => Ok(Mongolia),
+            "mo" | "macau" => Ok(Macau),
+            "mp" | "northern mariana islands" => Ok(NorthernMarianaIslands),
+            "mq" | "martinique" => Ok(Martinique),
+            "mr" | "mauritania" => Ok(Mauritania),
+            "ms" | "montserrat" => Ok(Montserrat),
+            "mt" | "malta" => Ok(Malta),
+            "mu" | "mauritius" => Ok(Mauritius),
+            "mv" | "maldives" => Ok(Maldives),
+            "mw" | "malawi" => Ok(Malawi),
+            "mx" | "mexico" => Ok(Mexico),
+            "my" | "malaysia" => Ok(Malaysia),
+            "mz" | "mozambique" => Ok(Mozambique),
+            "na" | "namibia" => Ok(Namibia),
+            "nc" | "new caledonia" => Ok(NewCaledonia),
+            "ne" | "niger" => Ok(Niger),
+            "nf" | "norfolk island" => Ok(NorfolkIsland),
+            "ng" | "nigeria" => Ok(Nigeria),
+            "ni" | "nicaragua" => Ok(Nicaragua),
+            "nl" | "netherlands" => Ok(Netherlands),
+            "no" | "norway" => Ok(Norway),
+            "np" | "nepal" => Ok(Nepal),
+            "nr" | "nauru" => Ok(Nauru),
+            "nu" | "niue" => Ok(Niue),
+            "nz" | "new zealand" => Ok(NewZealand),
+            "om" | "oman" => Ok(Oman),
+            "pa" | "panama" => Ok(Panama),
+            "pe" | "peru" => Ok(Peru),
+            "pf" | "french polynesia" => Ok(FrenchPolynesia),
+            "pg" | "new guinea" => Ok(NewGuinea),
+            "ph" | "philippines" => Ok(Philippines),
+            "pk" | "pakistan" => Ok(Pakistan),
+            "pl" | "poland" => Ok(Poland),
+            "pm" | "saint pierre" => Ok(SaintPierre),
+            "pn" | "pitcairn islands" => Ok(PitcairnIslands),
+            "pr" | "puerto rico" => Ok(PuertoRico),
+            "ps" | "palestine" => Ok(Palestine),
+            "pt" | "portugal" => Ok(Portugal),
+            "pw" | "palau" => Ok(Palau),
+            "py" | "paraguay" => Ok(Paraguay),
+            "qa" | "qatar" => Ok(Qatar),
+            "re" | "reunion" => Ok(Reunion),
+            "ro" | "romania" => Ok(Romania),
+            "rs" | "serbia" => Ok(Serbia),
+            "ru" | "russia" => Ok(Russia),
+            "rw" | "rwanda" => Ok(Rwanda),
+            "sa" | "saudi arabia" => Ok(SaudiArabia),
+            "sb" | "solomon islands" => Ok(SolomonIslands),
+            "sc" | "seychelles" => Ok(Seychelles),
+            "gb sct" | "scotland" => Ok(Scotland),
+            "sd" | "sudan" => Ok(Sudan),
+            "se" | "sweden" => Ok(Sweden),
+            "sg" | "singapore" => Ok(Singapore),
+            "sh" | "saint helena" => Ok(SaintHelena),
+            "si" | "slovenia" => Ok(Slovenia),
+            "sj" | "svalbard" | "jan mayen" => Ok(Svalbard),
+            "sk" | "slovakia" => Ok(Slovakia),
+            "sl" | "sierra leone" => Ok(SierraLeone),
+            "sm" | "san marino" => Ok(SanMarino),
+            "sn" | "senegal" => Ok(Senegal),
+            "so" | "somalia" => Ok(Somalia),
+            "sr" | "suriname" => Ok(Suriname),
+            "st" | "sao tome" => Ok(SaoTome),
+            "sv" | "el salvador" => Ok(ElSalvador),
+            "sy" | "syria" => Ok(Syria),
+            "sz" | "swaziland" => Ok(Swaziland),
+            "tc" | "caicos islands" => Ok(CaicosIslands),
+            "td" | "chad" => Ok(Chad),
+            "tf" | "french territories" => Ok(FrenchTerritories),
+            "tg" | "togo" => Ok(Togo),
+            "th" | "thailand" => Ok(Thailand),
+            "tj" | "tajikistan" => Ok(Tajikistan),
+            "tk" | "tokelau" => Ok(Tokelau),
+            "tl" | "timorleste" => Ok(Timorleste),
+            "tm" | "turkmenistan" => Ok(Turkmenistan),
+            "tn" | "tunisia" => Ok(Tunisia),
+            "to" | "tonga" => Ok(Tonga),
+            "tr" | "turkey" => Ok(Turkey),
+            "tt" | "trinidad" => Ok(Trinidad),
+            "tv" | "tuvalu" => Ok(Tuvalu),
+            "tw" | "taiwan" => Ok(Taiwan),
+            "tz" | "tanzania" => Ok(Tanzania),
+            "ua" | "ukraine" => Ok(Ukraine),
+            "ug" | "uganda" => Ok(Uganda),
+            "um" | "us minor islands" => Ok(UsMinorIslands),
+            "us" | "america" | "united states" => Ok(UnitedStates),
+            "uy" | "uruguay" => Ok(Uruguay),
+            "uz" | "uzbekistan" => Ok(Uzbekistan),
+            "va" | "vatican city" => Ok(VaticanCity),
+            "vc" | "saint vincent" => Ok(SaintVincent),
+            "ve" | "venezuela" => Ok(Venezuela),
+            "vg" | "british virgin islands" => Ok(BritishVirginIslands),
+            "vi" | "us virgin islands" => Ok(UsVirginIslands),
+            "vn" | "vietnam" => Ok(Vietnam),
+            "vu" | "vanuatu" => Ok(Vanuatu),
+            "gb wls" | "wales" => Ok(Wales),
+            "wf" | "wallis and futuna" => Ok(WallisAndFutuna),
+            "ws" | "samoa" => Ok(Samoa),
+            "ye" | "yemen" => Ok(Yemen),
+            "yt" | "mayotte" => Ok(Mayotte),
+            "za" | "south africa" => Ok(SouthAfrica),
+            "zm" | "zambia" => Ok(Zambia),
+            "zw" | "zimbabwe" => Ok(Zimbabwe),
+            _ => Err(ParseFlagNameError(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for ParseFlagNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown flag name `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseFlagNameError {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use FlagName::*;
+
+    const ALL: [FlagName; 245] = [
+        Andorra, UnitedArabEmirates, Afghanistan, Antigua, Anguilla, Albania, Armenia,
+        NetherlandsAntilles, Angola, Argentina, AmericanSamoa, Austria, Australia, Aruba,
+        AlandIslands, Azerbaijan, Bosnia, Barbados, Bangladesh, Belgium, BurkinaFaso, Bulgaria,
+        Bahrain, Burundi, Benin, Bermuda, Brunei, Bolivia, Brazil, Bahamas, Bhutan, BouvetIsland,
+        Botswana, Belarus, Belize, Canada, CocosIslands, Congo, CentralAfricanRepublic,
+        CongoBrazzaville, Switzerland, CoteDivoire, CookIslands, Chile, Cameroon, China, Colombia,
+        CostaRica, SerbiaAndMontenegro, Cuba, CapeVerde, ChristmasIsland, Cyprus, CzechRepublic,
+        Germany, Djibouti, Denmark, Dominica, DominicanRepublic, Algeria, Ecuador, Estonia, Egypt,
+        WesternSahara, England, Eritrea, Spain, Ethiopia, EuropeanUnion, Finland, Fiji,
+        FalklandIslands, Micronesia, FaroeIslands, France, Gabon, UnitedKingdom, Grenada, Georgia,
+        FrenchGuiana, Ghana, Gibraltar, Greenland, Gambia, Guinea, Guadeloupe, EquatorialGuinea,
+        Greece, SandwichIslands, Guatemala, Guam, GuineaBissau, Guyana, HongKong, HeardIsland,
+        Honduras, Croatia, Haiti, Hungary, Indonesia, Ireland, Israel, India, IndianOceanTerritory,
+        Iraq, Iran, Iceland, Italy, Jamaica, Jordan, Japan, Kenya, Kyrgyzstan, Cambodia, Kiribati,
+        Comoros, SaintKittsAndNevis, NorthKorea, SouthKorea, Kuwait, CaymanIslands, Kazakhstan,
+        Laos, Lebanon, SaintLucia, Liechtenstein, SriLanka, Liberia, Lesotho, Lithuania,
+        Luxembourg, Latvia, Libya, Morocco, Monaco, Moldova, Montenegro, Madagascar,
+        MarshallIslands, Macedonia, Mali, Myanmar, Mongolia, Macau, NorthernMarianaIslands,
+        Martinique, Mauritania, Montserrat, Malta, Mauritius, Maldives, Malawi, Mexico, Malaysia,
+        Mozambique, Namibia, NewCaledonia, Niger, NorfolkIsland, Nigeria, Nicaragua, Netherlands,
+        Norway, Nepal, Nauru, Niue, NewZealand, Oman, Panama, Peru, FrenchPolynesia, NewGuinea,
+        Philippines, Pakistan, Poland, SaintPierre, PitcairnIslands, PuertoRico, Palestine,
+        Portugal, Palau, Paraguay, Qatar, Reunion, Romania, Serbia, Russia, Rwanda, SaudiArabia,
+        SolomonIslands, Seychelles, Scotland, Sudan, Sweden, Singapore, SaintHelena, Slovenia,
+        Svalbard, Slovakia, SierraLeone, SanMarino, Senegal, Somalia, Suriname, SaoTome,
+        ElSalvador, Syria, Swaziland, CaicosIslands, Chad, FrenchTerritories, Togo, Thailand,
+        Tajikistan, Tokelau, Timorleste, Turkmenistan, Tunisia, Tonga, Turkey, Trinidad, Tuvalu,
+        Taiwan, Tanzania, Ukraine, Uganda, UsMinorIslands, UnitedStates, Uruguay, Uzbekistan,
+        VaticanCity, SaintVincent, Venezuela, BritishVirginIslands, UsVirginIslands, Vietnam,
+        Vanuatu, Wales, WallisAndFutuna, Samoa, Yemen, Mayotte, SouthAfrica, Zambia, Zimbabwe,
+    ];
+
+    const FULL_NAMES: [(FlagName, &str); 245] = [
+        (Andorra, "andorra"), (UnitedArabEmirates, "united arab emirates"),
+        (Afghanistan, "afghanistan"), (Antigua, "antigua"), (Anguilla, "anguilla"),
+        (Albania, "albania"), (Armenia, "armenia"), (NetherlandsAntilles, "netherlands antilles"),
+        (Angola, "angola"), (Argentina, "argentina"), (AmericanSamoa, "american samoa"),
+        (Austria, "austria"), (Australia, "australia"), (Aruba, "aruba"),
+        (AlandIslands, "aland islands"), (Azerbaijan, "azerbaijan"), (Bosnia, "bosnia"),
+        (Barbados, "barbados"), (Bangladesh, "bangladesh"), (Belgium, "belgium"),
+        (BurkinaFaso, "burkina faso"), (Bulgaria, "bulgaria"), (Bahrain, "bahrain"),
+        (Burundi, "burundi"), (Benin, "benin"), (Bermuda, "bermuda"), (Brunei, "brunei"),
+        (Bolivia, "bolivia"), (Brazil, "brazil"), (Bahamas, "bahamas"), (Bhutan, "bhutan"),
+        (BouvetIsland, "bouvet island"), (Botswana, "botswana"), (Belarus, "belarus"),
+        (Belize, "belize"), (Canada, "canada"), (CocosIslands, "cocos islands"), (Congo, "congo"),
+        (CentralAfricanRepublic, "central african republic"),
+        (CongoBrazzaville, "congo brazzaville"), (Switzerland, "switzerland"),
+        (CoteDivoire, "cote divoire"), (CookIslands, "cook islands"), (Chile, "chile"),
+        (Cameroon, "cameroon"), (China, "china"), (Colombia, "colombia"), (CostaRica, "costa rica"),
+        (SerbiaAndMontenegro, "serbia and montenegro"), (Cuba, "cuba"), (CapeVerde, "cape verde"),
+        (ChristmasIsland, "christmas island"), (Cyprus, "cyprus"),
+        (CzechRepublic, "czech republic"), (Germany, "germany"), (Djibouti, "djibouti"),
+        (Denmark, "denmark"), (Dominica, "dominica"), (DominicanRepublic, "dominican republic"),
+        (Algeria, "algeria"), (Ecuador, "ecuador"), (Estonia, "estonia"), (Egypt, "egypt"),
+        (WesternSahara, "western sahara"), (England, "england"), (Eritrea, "eritrea"),
+        (Spain, "spain"), (Ethiopia, "ethiopia"), (EuropeanUnion, "european union"),
+        (Finland, "finland"), (Fiji, "fiji"), (FalklandIslands, "falkland islands"),
+        (Micronesia, "micronesia"), (FaroeIslands, "faroe islands"), (France, "france"),
+        (Gabon, "gabon"), (UnitedKingdom, "united kingdom"), (Grenada, "grenada"),
+        (Georgia, "georgia"), (FrenchGuiana, "french guiana"), (Ghana, "ghana"),
+        (Gibraltar, "gibraltar"), (Greenland, "greenland"), (Gambia, "gambia"), (Guinea, "guinea"),
+        (Guadeloupe, "guadeloupe"), (EquatorialGuinea, "equatorial guinea"), (Greece, "greece"),
+        (SandwichIslands, "sandwich islands"), (Guatemala, "guatemala"), (Guam, "guam"),
+        (GuineaBissau, "guinea-bissau"), (Guyana, "guyana"), (HongKong, "hong kong"),
+        (HeardIsland, "heard island"), (Honduras, "honduras"), (Croatia, "croatia"),
+        (Haiti, "haiti"), (Hungary, "hungary"), (Indonesia, "indonesia"), (Ireland, "ireland"),
+        (Israel, "israel"), (India, "india"), (IndianOceanTerritory, "indian ocean territory"),
+        (Iraq, "iraq"), (Iran, "iran"), (Iceland, "iceland"), (Italy, "italy"),
+        (Jamaica, "jamaica"), (Jordan, "jordan"), (Japan, "japan"), (Kenya, "kenya"),
+        (Kyrgyzstan, "kyrgyzstan"), (Cambodia, "cambodia"), (Kiribati, "kiribati"),
+        (Comoros, "comoros"), (SaintKittsAndNevis, "saint kitts and nevis"),
+        (NorthKorea, "north korea"), (SouthKorea, "south korea"), (Kuwait, "kuwait"),
+        (CaymanIslands, "cayman islands"), (Kazakhstan, "kazakhstan"), (Laos, "laos"),
+        (Lebanon, "lebanon"), (SaintLucia, "saint lucia"), (Liechtenstein, "liechtenstein"),
+        (SriLanka, "sri lanka"), (Liberia, "liberia"), (Lesotho, "lesotho"),
+        (Lithuania, "lithuania"), (Luxembourg, "luxembourg"), (Latvia, "latvia"), (Libya, "libya"),
+        (Morocco, "morocco"), (Monaco, "monaco"), (Moldova, "moldova"), (Montenegro, "montenegro"),
+        (Madagascar, "madagascar"), (MarshallIslands, "marshall islands"), (Macedonia, "macedonia"),
+        (Mali, "mali"), (Myanmar, "myanmar"), (Mongolia, "mongolia"), (Macau, "macau"),
+        (NorthernMarianaIslands, "northern mariana islands"), (Martinique, "martinique"),
+        (Mauritania, "mauritania"), (Montserrat, "montserrat"), (Malta, "malta"),
+        (Mauritius, "mauritius"), (Maldives, "maldives"), (Malawi, "malawi"), (Mexico, "mexico"),
+        (Malaysia, "malaysia"), (Mozambique, "mozambique"), (Namibia, "namibia"),
+        (NewCaledonia, "new caledonia"), (Niger, "niger"), (NorfolkIsland, "norfolk island"),
+        (Nigeria, "nigeria"), (Nicaragua, "nicaragua"), (Netherlands, "netherlands"),
+        (Norway, "norway"), (Nepal, "nepal"), (Nauru, "nauru"), (Niue, "niue"),
+        (NewZealand, "new zealand"), (Oman, "oman"), (Panama, "panama"), (Peru, "peru"),
+        (FrenchPolynesia, "french polynesia"), (NewGuinea, "new guinea"),
+        (Philippines, "philippines"), (Pakistan, "pakistan"), (Poland, "poland"),
+        (SaintPierre, "saint pierre"), (PitcairnIslands, "pitcairn islands"),
+        (PuertoRico, "puerto rico"), (Palestine, "palestine"), (Portugal, "portugal"),
+        (Palau, "palau"), (Paraguay, "paraguay"), (Qatar, "qatar"), (Reunion, "reunion"),
+        (Romania, "romania"), (Serbia, "serbia"), (Russia, "russia"), (Rwanda, "rwanda"),
+        (SaudiArabia, "saudi arabia"), (SolomonIslands, "solomon islands"),
+        (Seychelles, "seychelles"), (Scotland, "scotland"), (Sudan, "sudan"), (Sweden, "sweden"),
+        (Singapore, "singapore"), (SaintHelena, "saint helena"), (Slovenia, "slovenia"),
+        (Svalbard, "svalbard"), (Slovakia, "slovakia"), (SierraLeone, "sierra leone"),
+        (SanMarino, "san marino"), (Senegal, "senegal"), (Somalia, "somalia"),
+        (Suriname, "suriname"), (SaoTome, "sao tome"), (ElSalvador, "el salvador"),
+        (Syria, "syria"), (Swaziland, "swaziland"), (CaicosIslands, "caicos islands"),
+        (Chad, "chad"), (FrenchTerritories, "french territories"), (Togo, "togo"),
+        (Thailand, "thailand"), (Tajikistan, "tajikistan"), (Tokelau, "tokelau"),
+        (Timorleste, "timorleste"), (Turkmenistan, "turkmenistan"), (Tunisia, "tunisia"),
+        (Tonga, "tonga"), (Turkey, "turkey"), (Trinidad, "trinidad"), (Tuvalu, "tuvalu"),
+        (Taiwan, "taiwan"), (Tanzania, "tanzania"), (Ukraine, "ukraine"), (Uganda, "uganda"),
+        (UsMinorIslands, "us minor islands"), (UnitedStates, "america"), (Uruguay, "uruguay"),
+        (Uzbekistan, "uzbekistan"), (VaticanCity, "vatican city"), (SaintVincent, "saint vincent"),
+        (Venezuela, "venezuela"), (BritishVirginIslands, "british virgin islands"),
+        (UsVirginIslands, "us virgin islands"), (Vietnam, "vietnam"), (Vanuatu, "vanuatu"),
+        (Wales, "wales"), (WallisAndFutuna, "wallis and futuna"), (Samoa, "samoa"),
+        (Yemen, "yemen"), (Mayotte, "mayotte"), (SouthAfrica, "south africa"), (Zambia, "zambia"),
+        (Zimbabwe, "zimbabwe"),
+    ];
+
+    #[test]
+    fn every_name_round_trips_through_display() {
+        for flag in ALL.iter() {
+            assert_eq!(flag.to_string().parse::<FlagName>(), Ok(*flag), "{:?}", flag);
+        }
+    }
+
+    #[test]
+    fn every_name_renders_a_distinct_code() {
+        let codes: HashSet<String> = ALL.iter().map(ToString::to_string).collect();
+        assert_eq!(codes.len(), ALL.len());
+    }
+
+    #[test]
+    fn every_name_parses_from_its_full_name() {
+        let named: HashSet<FlagName> = FULL_NAMES.iter().map(|(flag, _)| *flag).collect();
+        assert_eq!(named, ALL.iter().copied().collect());
+        for (flag, name) in FULL_NAMES.iter() {
+            assert_ne!(*name, flag.to_string(), "{:?}", flag);
+            assert_eq!(name.parse(), Ok(*flag), "{:?}", flag);
+        }
+    }
+
+    #[test]
+    fn aliases_parse() {
+        assert_eq!("america".parse(), Ok(UnitedStates));
+        assert_eq!("united states".parse(), Ok(UnitedStates));
+        assert_eq!("us".parse(), Ok(UnitedStates));
+        assert_eq!("gb eng".parse(), Ok(England));
+        assert_eq!("england".parse(), Ok(England));
+        assert_eq!("uae".parse(), Ok(UnitedArabEmirates));
+    }
+
+    #[test]
+    fn parsing_ignores_case_and_surrounding_whitespace() {
+        assert_eq!("US".parse(), Ok(UnitedStates));
+        assert_eq!("  United States\n".parse(), Ok(UnitedStates));
+        assert_eq!("\tGB ENG ".parse(), Ok(England));
+    }
+
+    #[test]
+    fn unknown_names_are_rejected() {
+        assert_eq!("atlantis".parse::<FlagName>(), Err(ParseFlagNameError("atlantis".to_string())));
+        assert!("".parse::<FlagName>().is_err());
+        assert_eq!(
+            "atlantis".parse::<FlagName>().unwrap_err().to_string(),
+            "unknown flag name `atlantis`"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod flag;
+mod flag_name;
+
+pub use flag::*;
+pub use flag_name::*;
\ No newline at end of file
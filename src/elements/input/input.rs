@@ -0,0 +1,316 @@
+use yew::prelude::*;
+use yew::web_sys::HtmlInputElement;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use crate::collections::{Button, Icon, Label};
+
+/// An Input is a field used to elicit a response from a user.
+/// Also see [`Button`](crate::collections::Button), [`Icon`](crate::collections::Icon), [`Label`](crate::collections::Label)
+pub struct Input {
+    link: ComponentLink<Self>,
+    props: InputProps,
+    classes: Vec<String>,
+}
+
+pub enum InputMsg {
+    Change(InputData),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InputActionPosition {
+    Left
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InputIconPosition {
+    Left
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InputLabelPosition {
+    Left,
+    Right,
+    LeftCorner,
+    RightCorner,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct InputProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// An Input can be formatted to alert the user to an action they may perform.
+    #[prop_or_default]
+    pub action: ChildrenWithProps<Button>,
+    /// An action can appear along side an Input on the left or right.
+    #[prop_or_else(|| None)]
+    pub action_position: Option<InputActionPosition>,
+    /// Primary content, used in place of the default `<input>`.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// An Input field can show that it is disabled.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// An Input field can show the data contains errors.
+    #[prop_or(false)]
+    pub error: bool,
+    /// Take on the size of its container.
+    #[prop_or(false)]
+    pub fluid: bool,
+    /// An Input field can show a user is currently interacting with it.
+    #[prop_or(false)]
+    pub focus: bool,
+    /// Add an Icon by pass an <Icon />.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<Icon>,
+    /// An Icon can appear inside an Input on the left or right.
+    #[prop_or_else(|| None)]
+    pub icon_position: Option<InputIconPosition>,
+    /// Reference to the underlying `<input>`, see [`Input::focus`] and [`Input::select`].
+    #[prop_or_default]
+    pub input_ref: NodeRef,
+    /// The HTML input type.
+    #[prop_or_else(|| "text".to_string())]
+    pub input_type: String,
+    /// Format to appear on dark backgrounds.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// Add a Label by pass a <Label />.
+    #[prop_or_default]
+    pub label: ChildrenWithProps<Label>,
+    /// A Label can appear outside an Input on the left or right.
+    #[prop_or_else(|| None)]
+    pub label_position: Option<InputLabelPosition>,
+    /// An Icon Input field can show that it is currently loading data.
+    #[prop_or(false)]
+    pub loading: bool,
+    /// Called on change with the event and the current value.
+    #[prop_or_default]
+    pub on_change: Callback<(InputEvent, String)>,
+    /// A hint to the user of what can be entered.
+    #[prop_or_else(|| None)]
+    pub placeholder: Option<String>,
+    /// An Input can vary in size.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// An Input can receive focus.
+    #[prop_or_else(|| None)]
+    pub tab_index: Option<isize>,
+    /// Transparent Input has no background.
+    #[prop_or(false)]
+    pub transparent: bool,
+    /// The value of the input.
+    #[prop_or_else(|| None)]
+    pub value: Option<String>,
+}
+
+impl Component for Input {
+    type Message = InputMsg;
+    type Properties = InputProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { link, props, classes }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let InputMsg::Change(data) = msg;
+        self.props.on_change.emit((data.event, data.value));
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                >
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        let action_left = self.props.action_position == Some(InputActionPosition::Left);
+        let label_right = self.props.label_position == Some(InputLabelPosition::Right)
+            || self.props.label_position == Some(InputLabelPosition::RightCorner);
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              { if action_left { html! { <>{ self.props.action.clone() }</> } } else { html! {} } }
+              { if !label_right { html! { <>{ self.props.label.clone() }</> } } else { html! {} } }
+              <input
+                ref=self.props.input_ref.clone()
+                type=self.props.input_type.clone()
+                disabled=self.props.disabled
+                placeholder=self.props.placeholder.clone()
+                tabindex=self.props.get_tab_index()
+                value=self.props.value.clone()
+                oninput=self.link.callback(InputMsg::Change)
+              />
+              { self.render_icon() }
+              { if !action_left { html! { <>{ self.props.action.clone() }</> } } else { html! {} } }
+              { if label_right { html! { <>{ self.props.label.clone() }</> } } else { html! {} } }
+            </@>
+        }
+    }
+}
+
+impl Input {
+    /// Moves the keyboard focus to the `<input>` behind the `input_ref` passed to an Input.
+    pub fn focus(input_ref: &NodeRef) {
+        if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+            let _ = input.focus();
+        }
+    }
+
+    /// Selects the text of the `<input>` behind the `input_ref` passed to an Input.
+    pub fn select(input_ref: &NodeRef) {
+        if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+            input.select();
+        }
+    }
+
+    fn render_icon(&self) -> Html {
+        if !self.props.icon.is_empty() {
+            html! { <>{ self.props.icon.clone() }</> }
+        } else if self.props.loading {
+            html! { <Icon name="spinner" /> }
+        } else {
+            html! {}
+        }
+    }
+}
+
+impl InputProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            action,
+            action_position,
+            class_name,
+            disabled,
+            error,
+            fluid,
+            focus,
+            icon,
+            icon_position,
+            inverted,
+            label,
+            label_position,
+            loading,
+            size,
+            transparent,
+            ..
+        } = self;
+
+        let mut action_class = if !action.is_empty() {
+            cx!(use_option(action_position), use_str("action"))
+        } else {
+            vec![]
+        };
+        let mut icon_class = if !icon.is_empty() || *loading {
+            cx!(use_option(icon_position), use_str("icon"))
+        } else {
+            vec![]
+        };
+        let mut labeled_class = if !label.is_empty() {
+            cx!(use_option(label_position), use_str("labeled"))
+        } else {
+            vec![]
+        };
+
+        cx!(
+            use_str("ui"),
+            use_option(size),
+            use_key(*disabled, "disabled"),
+            use_key(*error, "error"),
+            use_key(*fluid, "fluid"),
+            use_key(*focus, "focus"),
+            use_key(*inverted, "inverted"),
+            use_key(*loading, "loading"),
+            use_key(*transparent, "transparent"),
+            action_class,
+            icon_class,
+            labeled_class,
+            use_str("input"),
+            use_option(class_name)
+        )
+    }
+
+    fn get_tab_index(&self) -> Option<String> {
+        match self.tab_index {
+            Some(i) => Some(format!("{}", i)),
+            None => if self.disabled {
+                Some("-1".to_string())
+            } else {
+                None
+            }
+        }
+    }
+}
+
+impl From<InputActionPosition> for &'static str {
+    fn from(i: InputActionPosition) -> Self {
+        use InputActionPosition::*;
+
+        match i {
+            Left => "left",
+        }
+    }
+}
+
+impl AsRef<str> for InputActionPosition {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<InputIconPosition> for &'static str {
+    fn from(i: InputIconPosition) -> Self {
+        use InputIconPosition::*;
+
+        match i {
+            Left => "left",
+        }
+    }
+}
+
+impl AsRef<str> for InputIconPosition {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<InputLabelPosition> for &'static str {
+    fn from(i: InputLabelPosition) -> Self {
+        use InputLabelPosition::*;
+
+        match i {
+            Left => "left",
+            Right => "right",
+            LeftCorner => "left corner",
+            RightCorner => "right corner",
+        }
+    }
+}
+
+impl AsRef<str> for InputLabelPosition {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
@@ -0,0 +1,3 @@
+mod input;
+
+pub use input::*;
\ No newline at end of file
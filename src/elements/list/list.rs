@@ -0,0 +1,216 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::{ListItem, ListItemProps};
+
+/// A list groups related content.
+pub struct List {
+    props: ListProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ListRelaxed {
+    Very,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ListProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// A list can animate to set the current item apart from the list.
+    #[prop_or(false)]
+    pub animated: bool,
+    /// A list can mark items with a bullet.
+    #[prop_or(false)]
+    pub bulleted: bool,
+    /// A list can divide its items into cells.
+    #[prop_or(false)]
+    pub celled: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A list can show divisions between content.
+    #[prop_or(false)]
+    pub divided: bool,
+    /// A list can be floated left or right.
+    #[prop_or_else(|| None)]
+    pub floated: Option<sui::Float>,
+    /// A list can be formatted to have items appear horizontally.
+    #[prop_or(false)]
+    pub horizontal: bool,
+    /// A list can be inverted to appear on a dark background.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// Array of shorthand ListItem values.
+    #[prop_or_default]
+    pub items: Vec<ListItemProps>,
+    /// A list can be specially formatted for navigation links.
+    #[prop_or(false)]
+    pub link: bool,
+    /// Called when a shorthand item is clicked, with the index of the item.
+    #[prop_or_default]
+    pub on_item_click: Callback<(MouseEvent, usize)>,
+    /// A list can be ordered numerically.
+    #[prop_or(false)]
+    pub ordered: bool,
+    /// A list can relax its padding to provide more negative space.
+    #[prop_or_else(|| Either::Left(false))]
+    pub relaxed: Either<bool, ListRelaxed>,
+    /// A selection list formats list items as possible choices.
+    #[prop_or(false)]
+    pub selection: bool,
+    /// A list can vary in size.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// An element inside a list can be vertically aligned.
+    #[prop_or_else(|| None)]
+    pub vertical_align: Option<sui::VerticalAlign>,
+}
+
+impl Component for List {
+    type Message = ();
+    type Properties = ListProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                  role="list"
+                >
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        if !self.props.items.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                  role="list"
+                >
+                  {
+                      for self.props.items
+                          .iter()
+                          .enumerate()
+                          .map(|(index, item)| self.render_item(index, item))
+                  }
+                </@>
+            }
+        }
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+              role="list"
+            >
+              {
+                  match self.props.content {
+                      Some(ref content) => html! { content.clone() },
+                      None => html! {}
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl List {
+    fn render_item(&self, index: usize, item: &ListItemProps) -> Html {
+        let mut props = item.clone();
+        let on_list_click = self.props.on_item_click.clone();
+        let on_item_click = item.on_item_click.clone();
+        props.index = index;
+        props.on_item_click = Callback::from(move |(event, index): (MouseEvent, usize)| {
+            on_item_click.emit((event.clone(), index));
+            on_list_click.emit((event, index));
+        });
+        html! { <ListItem with props /> }
+    }
+}
+
+impl ListProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            animated,
+            bulleted,
+            celled,
+            class_name,
+            divided,
+            floated,
+            horizontal,
+            inverted,
+            link,
+            ordered,
+            relaxed,
+            selection,
+            size,
+            vertical_align,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(size),
+            use_key(*animated, "animated"),
+            use_key(*bulleted, "bulleted"),
+            use_key(*celled, "celled"),
+            use_key(*divided, "divided"),
+            use_key(*horizontal, "horizontal"),
+            use_key(*inverted, "inverted"),
+            use_key(*link, "link"),
+            use_key(*ordered, "ordered"),
+            use_key(*selection, "selection"),
+            use_key_or_option_and_key(relaxed, "relaxed"),
+            use_option_and_key(floated, "floated"),
+            use_option_and_key(vertical_align, "aligned"),
+            use_str("list"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<ListRelaxed> for &'static str {
+    fn from(l: ListRelaxed) -> Self {
+        use ListRelaxed::*;
+
+        match l {
+            Very => "very",
+        }
+    }
+}
+
+impl AsRef<str> for ListRelaxed {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
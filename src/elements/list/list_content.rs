@@ -0,0 +1,118 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::{ListDescription, ListHeader};
+
+/// A list item can contain a content.
+pub struct ListContent {
+    props: ListContentProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ListContentProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Shorthand for ListDescription.
+    #[prop_or_else(|| None)]
+    pub description: Option<String>,
+    /// A list content can be floated left or right.
+    #[prop_or_else(|| None)]
+    pub floated: Option<sui::Float>,
+    /// Shorthand for ListHeader.
+    #[prop_or_else(|| None)]
+    pub header: Option<String>,
+    /// An element inside a list can be vertically aligned.
+    #[prop_or_else(|| None)]
+    pub vertical_align: Option<sui::VerticalAlign>,
+}
+
+impl Component for ListContent {
+    type Message = ();
+    type Properties = ListContentProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                >
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  match self.props.header {
+                      Some(ref header) => html! { <ListHeader content=header.clone() /> },
+                      None => html! {}
+                  }
+              }
+              {
+                  match self.props.description {
+                      Some(ref description) => html! { <ListDescription content=description.clone() /> },
+                      None => html! {}
+                  }
+              }
+              {
+                  match self.props.content {
+                      Some(ref content) => html! { content.clone() },
+                      None => html! {}
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl ListContentProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            floated,
+            vertical_align,
+            ..
+        } = self;
+
+        cx!(
+            use_option_and_key(floated, "floated"),
+            use_option_and_key(vertical_align, "aligned"),
+            use_str("content"),
+            use_option(class_name)
+        )
+    }
+}
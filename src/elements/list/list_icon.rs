@@ -0,0 +1,36 @@
+use yew::prelude::*;
+
+use crate::collections::Icon;
+use crate::collections::icon::IconProps;
+
+/// A list item can contain an icon.
+pub struct ListIcon {
+    props: IconProps,
+}
+
+impl Component for ListIcon {
+    type Message = ();
+    type Properties = IconProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let props = self.props.clone();
+        html! { <Icon with props /> }
+    }
+}
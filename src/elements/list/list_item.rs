@@ -0,0 +1,192 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use crate::collections::Image;
+use crate::collections::image::ImageProps;
+use super::{ListContent, ListDescription, ListHeader, ListIcon};
+
+/// A list item can contain a set of items.
+pub struct ListItem {
+    link: ComponentLink<Self>,
+    props: ListItemProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ListItemProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// A list item can active.
+    #[prop_or(false)]
+    pub active: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Shorthand for ListDescription.
+    #[prop_or_else(|| None)]
+    pub description: Option<String>,
+    /// A list item can disabled.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// Shorthand for ListHeader.
+    #[prop_or_else(|| None)]
+    pub header: Option<String>,
+    /// Add a ListIcon by pass a <ListIcon />.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<ListIcon>,
+    /// Shorthand for Image.
+    #[prop_or_else(|| None)]
+    pub image: Option<ImageProps>,
+    /// Position of the item in its list, reported by `on_item_click`.
+    #[prop_or_default]
+    pub index: usize,
+    /// Called on click.
+    #[prop_or_default]
+    pub on_click: Callback<MouseEvent>,
+    /// Called on click with the index of the item.
+    #[prop_or_default]
+    pub on_item_click: Callback<(MouseEvent, usize)>,
+    /// A value for an ordered list.
+    #[prop_or_else(|| None)]
+    pub value: Option<String>,
+}
+
+impl Component for ListItem {
+    type Message = MouseEvent;
+    type Properties = ListItemProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { link, props, classes }
+    }
+
+    fn update(&mut self, event: Self::Message) -> ShouldRender {
+        if self.props.disabled {
+            event.prevent_default();
+        } else {
+            self.props.on_click.emit(event.clone());
+            self.props.on_item_click.emit((event, self.props.index));
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let (value, data_value) = if self.props.root == "li" {
+            (self.props.value.clone(), None)
+        } else {
+            (None, self.props.value.clone())
+        };
+
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                  onclick=self.link.callback(|e| e)
+                  role="listitem"
+                  value=value
+                  data-value=data_value
+                >
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        let header = match self.props.header {
+            Some(ref header) => html! { <ListHeader content=header.clone() /> },
+            None => html! {}
+        };
+        let description = match self.props.description {
+            Some(ref description) => html! { <ListDescription content=description.clone() /> },
+            None => html! {}
+        };
+        let content = match self.props.content {
+            Some(ref content) => html! { content.clone() },
+            None => html! {}
+        };
+
+        if self.props.icon.is_empty() && self.props.image.is_none() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                  onclick=self.link.callback(|e| e)
+                  role="listitem"
+                  value=value
+                  data-value=data_value
+                >
+                  { header }
+                  { description }
+                  { content }
+                </@>
+            }
+        }
+
+        let has_content = self.props.header.is_some()
+            || self.props.description.is_some()
+            || self.props.content.is_some();
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+              onclick=self.link.callback(|e| e)
+              role="listitem"
+              value=value
+              data-value=data_value
+            >
+              {
+                  if !self.props.icon.is_empty() {
+                      html! { <>{ self.props.icon.clone() }</> }
+                  } else {
+                      match self.props.image {
+                          Some(ref image) => html! { <Image with image.clone() /> },
+                          None => html! {}
+                      }
+                  }
+              }
+              {
+                  if has_content {
+                      html! { <ListContent>{ header }{ description }{ content }</ListContent> }
+                  } else {
+                      html! {}
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl ListItemProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            active,
+            class_name,
+            disabled,
+            root,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*active, "active"),
+            use_key(*disabled, "disabled"),
+            use_key(root != "li", "item"),
+            use_option(class_name)
+        )
+    }
+}
@@ -0,0 +1,13 @@
+mod list;
+mod list_content;
+mod list_description;
+mod list_header;
+mod list_icon;
+mod list_item;
+
+pub use list::*;
+pub use list_content::*;
+pub use list_description::*;
+pub use list_header::*;
+pub use list_icon::*;
+pub use list_item::*;
\ No newline at end of file
@@ -0,0 +1,140 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+
+/// A loader alerts a user to wait for an activity to complete.
+/// Also see [`Segment`](crate::elements::Segment)
+pub struct Loader {
+    props: LoaderProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoaderInline {
+    Centered,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct LoaderProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// A loader can be active or visible.
+    #[prop_or(false)]
+    pub active: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A loader can be disabled or hidden.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// A loader can show it's unsure of how long a task will take.
+    #[prop_or(false)]
+    pub indeterminate: bool,
+    /// Loaders can appear inline with content.
+    #[prop_or_else(|| Either::Left(false))]
+    pub inline: Either<bool, LoaderInline>,
+    /// Loaders can have their colors inverted.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// Loaders can have different sizes.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+}
+
+impl Component for Loader {
+    type Message = ();
+    type Properties = LoaderProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl LoaderProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            active,
+            children,
+            class_name,
+            content,
+            disabled,
+            indeterminate,
+            inline,
+            inverted,
+            size,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(size),
+            use_key(*active, "active"),
+            use_key(*disabled, "disabled"),
+            use_key(*indeterminate, "indeterminate"),
+            use_key(*inverted, "inverted"),
+            use_key(!children.is_empty() || content.is_some(), "text"),
+            use_key_or_option_and_key(inline, "inline"),
+            use_str("loader"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<LoaderInline> for &'static str {
+    fn from(l: LoaderInline) -> Self {
+        use LoaderInline::*;
+
+        match l {
+            Centered => "centered",
+        }
+    }
+}
+
+impl AsRef<str> for LoaderInline {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
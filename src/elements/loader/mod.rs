@@ -0,0 +1,3 @@
+mod loader;
+
+pub use loader::*;
\ No newline at end of file
@@ -0,0 +1,23 @@
+pub mod divider;
+pub mod flag;
+pub mod input;
+pub mod list;
+pub mod loader;
+pub mod placeholder;
+pub mod rail;
+pub mod reveal;
+pub mod segment;
+pub mod step;
+
+pub use divider::Divider;
+pub use flag::{Flag, FlagName};
+pub use input::Input;
+pub use list::{List, ListContent, ListDescription, ListHeader, ListIcon, ListItem};
+pub use loader::Loader;
+pub use placeholder::{
+    Placeholder, PlaceholderHeader, PlaceholderImage, PlaceholderLine, PlaceholderParagraph,
+};
+pub use rail::Rail;
+pub use reveal::{Reveal, RevealContent};
+pub use segment::{Segment, SegmentInline, Segments};
+pub use step::{Step, StepContent, StepDescription, StepGroup, StepTitle};
\ No newline at end of file
@@ -0,0 +1,11 @@
+mod placeholder;
+mod placeholder_header;
+mod placeholder_image;
+mod placeholder_line;
+mod placeholder_paragraph;
+
+pub use placeholder::*;
+pub use placeholder_header::*;
+pub use placeholder_image::*;
+pub use placeholder_line::*;
+pub use placeholder_paragraph::*;
\ No newline at end of file
@@ -0,0 +1,133 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use super::{PlaceholderHeader, PlaceholderImage, PlaceholderLine, PlaceholderParagraph};
+
+/// A placeholder is used to reserve space for content that soon will appear in a layout.
+pub struct Placeholder {
+    props: PlaceholderProps,
+    classes: Vec<String>,
+}
+
+/// A block of a placeholder layout, see [`PlaceholderProps::layout`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlaceholderBlock {
+    /// A header of `lines` lines, optionally next to an image.
+    Header { image: bool, lines: usize },
+    /// A paragraph of `lines` lines.
+    Paragraph { lines: usize },
+    /// An image, optionally square or rectangular.
+    Image { square: bool, rectangular: bool },
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct PlaceholderProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A fluid placeholder takes up the width of its container.
+    #[prop_or(false)]
+    pub fluid: bool,
+    /// A placeholder can have their colors inverted.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// Shorthand for the placeholder content, e.g. a header followed by a paragraph of three lines.
+    #[prop_or_default]
+    pub layout: Vec<PlaceholderBlock>,
+}
+
+impl Component for Placeholder {
+    type Message = ();
+    type Properties = PlaceholderProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else if !self.props.layout.is_empty() {
+                      html! { for self.props.layout.iter().map(|block| self.render_block(block)) }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl Placeholder {
+    fn render_block(&self, block: &PlaceholderBlock) -> Html {
+        use PlaceholderBlock::*;
+
+        match *block {
+            Header { image, lines } => html! {
+                <PlaceholderHeader image=image>
+                  { for (0..lines).map(|_| html! { <PlaceholderLine /> }) }
+                </PlaceholderHeader>
+            },
+            Paragraph { lines } => html! {
+                <PlaceholderParagraph>
+                  { for (0..lines).map(|_| html! { <PlaceholderLine /> }) }
+                </PlaceholderParagraph>
+            },
+            Image { square, rectangular } => html! {
+                <PlaceholderImage square=square rectangular=rectangular />
+            },
+        }
+    }
+}
+
+impl PlaceholderProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            fluid,
+            inverted,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_key(*fluid, "fluid"),
+            use_key(*inverted, "inverted"),
+            use_str("placeholder"),
+            use_option(class_name)
+        )
+    }
+}
@@ -0,0 +1,88 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+
+/// A placeholder can contain a header.
+pub struct PlaceholderHeader {
+    props: PlaceholderHeaderProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct PlaceholderHeaderProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A placeholder can contain an image.
+    #[prop_or(false)]
+    pub image: bool,
+}
+
+impl Component for PlaceholderHeader {
+    type Message = ();
+    type Properties = PlaceholderHeaderProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl PlaceholderHeaderProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            image,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*image, "image"),
+            use_str("header"),
+            use_option(class_name)
+        )
+    }
+}
@@ -0,0 +1,76 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+
+/// A placeholder can contain an image.
+pub struct PlaceholderImage {
+    props: PlaceholderImageProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct PlaceholderImageProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// An image can modify size correctly with responsive styles.
+    #[prop_or(false)]
+    pub rectangular: bool,
+    /// An image can modify size correctly with responsive styles.
+    #[prop_or(false)]
+    pub square: bool,
+}
+
+impl Component for PlaceholderImage {
+    type Message = ();
+    type Properties = PlaceholderImageProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            />
+        }
+    }
+}
+
+impl PlaceholderImageProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            rectangular,
+            square,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*square, "square"),
+            use_key(*rectangular, "rectangular"),
+            use_str("image"),
+            use_option(class_name)
+        )
+    }
+}
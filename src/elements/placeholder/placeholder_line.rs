@@ -0,0 +1,102 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+
+/// A placeholder can contain lines of text.
+pub struct PlaceholderLine {
+    props: PlaceholderLineProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlaceholderLineLength {
+    Full,
+    VeryLong,
+    Long,
+    Medium,
+    Short,
+    VeryShort,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct PlaceholderLineProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A line can specify how long its contents should appear.
+    #[prop_or_else(|| None)]
+    pub length: Option<PlaceholderLineLength>,
+}
+
+impl Component for PlaceholderLine {
+    type Message = ();
+    type Properties = PlaceholderLineProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            />
+        }
+    }
+}
+
+impl PlaceholderLineProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            length,
+            ..
+        } = self;
+
+        cx!(
+            use_option(length),
+            use_str("line"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<PlaceholderLineLength> for &'static str {
+    fn from(p: PlaceholderLineLength) -> Self {
+        use PlaceholderLineLength::*;
+
+        match p {
+            Full => "full",
+            VeryLong => "very long",
+            Long => "long",
+            Medium => "medium",
+            Short => "short",
+            VeryShort => "very short",
+        }
+    }
+}
+
+impl AsRef<str> for PlaceholderLineLength {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
@@ -0,0 +1,3 @@
+mod rail;
+
+pub use rail::*;
\ No newline at end of file
@@ -0,0 +1,158 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+
+/// A rail is used to show accompanying content outside the boundaries of the main view of a site.
+pub struct Rail {
+    props: RailProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RailClose {
+    Very,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RailPosition {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct RailProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// A rail can appear attached to the main viewport.
+    #[prop_or(false)]
+    pub attached: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A rail can appear closer to the main viewport.
+    #[prop_or_else(|| Either::Left(false))]
+    pub close: Either<bool, RailClose>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A rail can create a division between itself and a container.
+    #[prop_or(false)]
+    pub dividing: bool,
+    /// A rail can attach itself to the inside of a container.
+    #[prop_or(false)]
+    pub internal: bool,
+    /// A rail can be presented on the left or right side of a container.
+    pub position: RailPosition,
+    /// A rail can have different sizes.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+}
+
+impl Component for Rail {
+    type Message = ();
+    type Properties = RailProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl RailProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            attached,
+            class_name,
+            close,
+            dividing,
+            internal,
+            position,
+            size,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_str(position),
+            use_option(size),
+            use_key(*attached, "attached"),
+            use_key(*dividing, "dividing"),
+            use_key(*internal, "internal"),
+            use_key_or_option_and_key(close, "close"),
+            use_str("rail"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<RailClose> for &'static str {
+    fn from(r: RailClose) -> Self {
+        use RailClose::*;
+
+        match r {
+            Very => "very",
+        }
+    }
+}
+
+impl AsRef<str> for RailClose {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<RailPosition> for &'static str {
+    fn from(r: RailPosition) -> Self {
+        use RailPosition::*;
+
+        match r {
+            Left => "left",
+            Right => "right",
+        }
+    }
+}
+
+impl AsRef<str> for RailPosition {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
@@ -0,0 +1,5 @@
+mod reveal;
+mod reveal_content;
+
+pub use reveal::*;
+pub use reveal_content::*;
\ No newline at end of file
@@ -0,0 +1,139 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+
+/// A reveal displays additional content in place of previous content when activated.
+pub struct Reveal {
+    props: RevealProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RevealAnimation {
+    Fade,
+    SmallFade,
+    Move,
+    MoveRight,
+    MoveUp,
+    MoveDown,
+    Rotate,
+    RotateLeft,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct RevealProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// An active reveal displays its hidden content.
+    #[prop_or(false)]
+    pub active: bool,
+    /// An animation name that will be applied to Reveal.
+    #[prop_or_else(|| None)]
+    pub animated: Option<RevealAnimation>,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A disabled reveal will not animate when hovered.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// An element can show its content without delay.
+    #[prop_or(false)]
+    pub instant: bool,
+}
+
+impl Component for Reveal {
+    type Message = ();
+    type Properties = RevealProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl RevealProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            active,
+            animated,
+            class_name,
+            disabled,
+            instant,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(animated),
+            use_key(*active, "active"),
+            use_key(*disabled, "disabled"),
+            use_key(*instant, "instant"),
+            use_str("reveal"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<RevealAnimation> for &'static str {
+    fn from(r: RevealAnimation) -> Self {
+        use RevealAnimation::*;
+
+        match r {
+            Fade => "fade",
+            SmallFade => "small fade",
+            Move => "move",
+            MoveRight => "move right",
+            MoveUp => "move up",
+            MoveDown => "move down",
+            Rotate => "rotate",
+            RotateLeft => "rotate left",
+        }
+    }
+}
+
+impl AsRef<str> for RevealAnimation {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
@@ -0,0 +1,7 @@
+mod segment;
+mod segment_inline;
+mod segments;
+
+pub use segment::*;
+pub use segment_inline::*;
+pub use segments::*;
\ No newline at end of file
@@ -0,0 +1,230 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+
+/// A segment is used to create a grouping of related content.
+/// Also see [`Segments`](crate::elements::Segments), [`SegmentInline`](crate::elements::SegmentInline)
+pub struct Segment {
+    props: SegmentProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SegmentAttachedPosition {
+    Top,
+    Bottom,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SegmentPadded {
+    Very
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct SegmentProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Attach segment to other content, like a header.
+    #[prop_or_else(|| Either::Left(false))]
+    pub attached: Either<bool, SegmentAttachedPosition>,
+    /// A basic segment has no special formatting.
+    #[prop_or(false)]
+    pub basic: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// A segment can be circular.
+    #[prop_or(false)]
+    pub circular: bool,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A segment can clear floated content.
+    #[prop_or(false)]
+    pub clearing: bool,
+    /// Segment can be colored.
+    #[prop_or_else(|| None)]
+    pub color: Option<sui::Colors>,
+    /// A segment may take up only as much space as is necessary.
+    #[prop_or(false)]
+    pub compact: bool,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A segment may show its content is disabled.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// Segment content can be floated to the left or right.
+    #[prop_or_else(|| None)]
+    pub floated: Option<sui::Float>,
+    /// A segment can have its colors inverted for contrast.
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// A segment may show its content is being loaded.
+    #[prop_or(false)]
+    pub loading: bool,
+    /// A segment can increase its padding.
+    #[prop_or_else(|| Either::Left(false))]
+    pub padded: Either<bool, SegmentPadded>,
+    /// A segment can be used to reserve space for conditionally displayed content.
+    #[prop_or(false)]
+    pub placeholder: bool,
+    /// Formatted to look like a pile of pages.
+    #[prop_or(false)]
+    pub piled: bool,
+    /// A segment may be formatted to raise above the page.
+    #[prop_or(false)]
+    pub raised: bool,
+    /// A segment can be formatted to appear less noticeable.
+    #[prop_or(false)]
+    pub secondary: bool,
+    /// A segment can have different sizes.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// Formatted to show it contains multiple pages.
+    #[prop_or(false)]
+    pub stacked: bool,
+    /// A segment can be formatted to appear even less noticeable.
+    #[prop_or(false)]
+    pub tertiary: bool,
+    /// Formats content to be aligned as part of a vertical group.
+    #[prop_or_else(|| None)]
+    pub text_align: Option<sui::TextAlign>,
+    /// Formats content to be aligned vertically.
+    #[prop_or(false)]
+    pub vertical: bool,
+}
+
+impl Component for Segment {
+    type Message = ();
+    type Properties = SegmentProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl SegmentProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            attached,
+            basic,
+            circular,
+            class_name,
+            clearing,
+            color,
+            compact,
+            disabled,
+            floated,
+            inverted,
+            loading,
+            padded,
+            placeholder,
+            piled,
+            raised,
+            secondary,
+            size,
+            stacked,
+            tertiary,
+            text_align,
+            vertical,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(color),
+            use_option(size),
+            use_key(*basic, "basic"),
+            use_key(*circular, "circular"),
+            use_key(*clearing, "clearing"),
+            use_key(*compact, "compact"),
+            use_key(*disabled, "disabled"),
+            use_key(*inverted, "inverted"),
+            use_key(*loading, "loading"),
+            use_key(*placeholder, "placeholder"),
+            use_key(*piled, "piled"),
+            use_key(*raised, "raised"),
+            use_key(*secondary, "secondary"),
+            use_key(*stacked, "stacked"),
+            use_key(*tertiary, "tertiary"),
+            use_key(*vertical, "vertical"),
+            use_key_or_option_and_key(attached, "attached"),
+            use_key_or_option_and_key(padded, "padded"),
+            use_option_text_align(text_align),
+            use_option_and_key(floated, "floated"),
+            use_str("segment"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<SegmentAttachedPosition> for &'static str {
+    fn from(s: SegmentAttachedPosition) -> Self {
+        use SegmentAttachedPosition::*;
+
+        match s {
+            Top => "top",
+            Bottom => "bottom",
+        }
+    }
+}
+
+impl AsRef<str> for SegmentAttachedPosition {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<SegmentPadded> for &'static str {
+    fn from(s: SegmentPadded) -> Self {
+        use SegmentPadded::*;
+
+        match s {
+            Very => "very",
+        }
+    }
+}
+
+impl AsRef<str> for SegmentPadded {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
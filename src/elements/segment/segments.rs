@@ -0,0 +1,114 @@
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+
+/// A group of segments can be formatted to appear together.
+pub struct Segments {
+    props: SegmentsProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct SegmentsProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A segment may take up only as much space as is necessary.
+    #[prop_or(false)]
+    pub compact: bool,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Formats content to be aligned horizontally.
+    #[prop_or(false)]
+    pub horizontal: bool,
+    /// Formatted to look like a pile of pages.
+    #[prop_or(false)]
+    pub piled: bool,
+    /// A segment group may be formatted to raise above the page.
+    #[prop_or(false)]
+    pub raised: bool,
+    /// A segment group can have different sizes.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// Formatted to show it contains multiple pages.
+    #[prop_or(false)]
+    pub stacked: bool,
+}
+
+impl Component for Segments {
+    type Message = ();
+    type Properties = SegmentsProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl SegmentsProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            class_name,
+            compact,
+            horizontal,
+            piled,
+            raised,
+            size,
+            stacked,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(size),
+            use_key(*compact, "compact"),
+            use_key(*horizontal, "horizontal"),
+            use_key(*piled, "piled"),
+            use_key(*raised, "raised"),
+            use_key(*stacked, "stacked"),
+            use_str("segments"),
+            use_option(class_name)
+        )
+    }
+}
@@ -0,0 +1,11 @@
+mod step;
+mod step_content;
+mod step_description;
+mod step_group;
+mod step_title;
+
+pub use step::*;
+pub use step_content::*;
+pub use step_description::*;
+pub use step_group::*;
+pub use step_title::*;
\ No newline at end of file
@@ -0,0 +1,150 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use crate::collections::Icon;
+use super::StepContent;
+
+/// A step shows the completion status of an activity in a series of activities.
+/// Also see [`StepGroup`](crate::elements::StepGroup)
+pub struct Step {
+    link: ComponentLink<Self>,
+    props: StepProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct StepProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// A step can be highlighted as active.
+    #[prop_or(false)]
+    pub active: bool,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// A step can show that a user has completed it.
+    #[prop_or(false)]
+    pub completed: bool,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Shorthand for StepDescription.
+    #[prop_or_else(|| None)]
+    pub description: Option<String>,
+    /// Show that the step is inactive.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// Render as an `a` tag instead of a `div` and adds the href attribute.
+    #[prop_or_else(|| None)]
+    pub href: Option<String>,
+    /// Add an Icon by pass an <Icon />.
+    #[prop_or_default]
+    pub icon: ChildrenWithProps<Icon>,
+    /// Position of the step in its group, reported by `on_click`.
+    #[prop_or_default]
+    pub index: usize,
+    /// A step can be link.
+    #[prop_or(false)]
+    pub link: bool,
+    /// Called on click with the index of the step.
+    #[prop_or_default]
+    pub on_click: Callback<(MouseEvent, usize)>,
+    /// Shorthand for StepTitle.
+    #[prop_or_else(|| None)]
+    pub title: Option<String>,
+}
+
+impl Component for Step {
+    type Message = MouseEvent;
+    type Properties = StepProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { link, props, classes }
+    }
+
+    fn update(&mut self, event: Self::Message) -> ShouldRender {
+        if self.props.disabled {
+            event.prevent_default();
+        } else {
+            self.props.on_click.emit((event, self.props.index));
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let root_element = self.props.get_element_type();
+
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ root_element }
+                  class=classes!(self.classes.as_slice())
+                  href=self.props.href.clone()
+                  onclick=self.link.callback(|e| e)
+                >
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        html! {
+            <@{ root_element }
+              class=classes!(self.classes.as_slice())
+              href=self.props.href.clone()
+              onclick=self.link.callback(|e| e)
+            >
+              { self.props.icon.clone() }
+              <StepContent
+                title=self.props.title.clone()
+                description=self.props.description.clone()
+                content=self.props.content.clone()
+              />
+            </@>
+        }
+    }
+}
+
+impl StepProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            active,
+            class_name,
+            completed,
+            disabled,
+            link,
+            ..
+        } = self;
+
+        cx!(
+            use_key(*active, "active"),
+            use_key(*completed, "completed"),
+            use_key(*disabled, "disabled"),
+            use_key(*link, "link"),
+            use_str("step"),
+            use_option(class_name)
+        )
+    }
+
+    fn get_element_type(&self) -> String {
+        if self.root == "div" && self.href.is_some() {
+            "a".to_string()
+        } else {
+            self.root.clone()
+        }
+    }
+}
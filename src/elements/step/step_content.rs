@@ -0,0 +1,103 @@
+use yew::prelude::*;
+
+use crate::cx;
+use crate::helper::*;
+use super::{StepDescription, StepTitle};
+
+/// A step can contain a content.
+pub struct StepContent {
+    props: StepContentProps,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct StepContentProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// Shorthand for StepDescription.
+    #[prop_or_else(|| None)]
+    pub description: Option<String>,
+    /// Shorthand for StepTitle.
+    #[prop_or_else(|| None)]
+    pub title: Option<String>,
+}
+
+impl Component for StepContent {
+    type Message = ();
+    type Properties = StepContentProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if !self.props.children.is_empty() {
+            return html! {
+                <@{ self.props.root.clone() }
+                  class=classes!(self.classes.as_slice())
+                >
+                  { self.props.children.clone() }
+                </@>
+            }
+        }
+
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  match self.props.title {
+                      Some(ref title) => html! { <StepTitle content=title.clone() /> },
+                      None => html! {}
+                  }
+              }
+              {
+                  match self.props.description {
+                      Some(ref description) => html! { <StepDescription content=description.clone() /> },
+                      None => html! {}
+                  }
+              }
+              {
+                  match self.props.content {
+                      Some(ref content) => html! { content.clone() },
+                      None => html! {}
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl StepContentProps {
+    fn derive_classes(&self) -> Vec<String> {
+        cx!(
+            use_str("content"),
+            use_option(&self.class_name)
+        )
+    }
+}
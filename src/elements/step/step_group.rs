@@ -0,0 +1,199 @@
+use either::Either;
+use yew::prelude::*;
+
+use crate::{cx, sui};
+use crate::helper::*;
+use super::{Step, StepProps};
+
+/// A set of steps.
+pub struct StepGroup {
+    props: StepGroupProps,
+    classes: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StepGroupAttachedPosition {
+    Top,
+    Bottom,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StepGroupStackable {
+    Tablet,
+    Computer,
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct StepGroupProps {
+    /// An html element type to render as root element.
+    #[prop_or_else(|| "div".to_string())]
+    pub root: String,
+    /// Steps can be attached to other elements.
+    #[prop_or_else(|| Either::Left(false))]
+    pub attached: Either<bool, StepGroupAttachedPosition>,
+    /// Primary content.
+    #[prop_or_default]
+    pub children: Children,
+    /// Additional classes.
+    #[prop_or_else(|| None)]
+    pub class_name: Option<String>,
+    /// Shorthand for primary content.
+    #[prop_or_else(|| None)]
+    pub content: Option<String>,
+    /// A fluid step takes up the width of its container.
+    #[prop_or(false)]
+    pub fluid: bool,
+    /// Array of shorthand Step values.
+    #[prop_or_default]
+    pub items: Vec<StepProps>,
+    /// Called when a shorthand step is clicked, with the index of the step.
+    #[prop_or_default]
+    pub on_step_click: Callback<(MouseEvent, usize)>,
+    /// A step can show an ordered sequence of steps.
+    #[prop_or(false)]
+    pub ordered: bool,
+    /// Steps can have different sizes.
+    #[prop_or_else(|| None)]
+    pub size: Option<sui::Sizes>,
+    /// A step can stack vertically only on smaller screens.
+    #[prop_or_else(|| None)]
+    pub stackable: Option<StepGroupStackable>,
+    /// A step can prevent itself from stacking on mobile.
+    #[prop_or(false)]
+    pub unstackable: bool,
+    /// A step can be displayed stacked vertically.
+    #[prop_or(false)]
+    pub vertical: bool,
+    /// Steps can be divided evenly inside their parent.
+    #[prop_or_else(|| None)]
+    pub widths: Option<sui::Widths>,
+}
+
+impl Component for StepGroup {
+    type Message = ();
+    type Properties = StepGroupProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let classes = props.derive_classes();
+        Self { props, classes }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            self.classes = self.props.derive_classes();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <@{ self.props.root.clone() }
+              class=classes!(self.classes.as_slice())
+            >
+              {
+                  if !self.props.children.is_empty() {
+                      html! { <>{ self.props.children.clone() }</> }
+                  } else if !self.props.items.is_empty() {
+                      html! {
+                          for self.props.items
+                              .iter()
+                              .enumerate()
+                              .map(|(index, item)| self.render_item(index, item))
+                      }
+                  } else {
+                      match self.props.content {
+                          Some(ref content) => html! { content.clone() },
+                          None => html! {}
+                      }
+                  }
+              }
+            </@>
+        }
+    }
+}
+
+impl StepGroup {
+    fn render_item(&self, index: usize, item: &StepProps) -> Html {
+        let mut props = item.clone();
+        let on_group_click = self.props.on_step_click.clone();
+        let on_step_click = item.on_click.clone();
+        props.index = index;
+        props.on_click = Callback::from(move |(event, index): (MouseEvent, usize)| {
+            on_step_click.emit((event.clone(), index));
+            on_group_click.emit((event, index));
+        });
+        html! { <Step with props /> }
+    }
+}
+
+impl StepGroupProps {
+    fn derive_classes(&self) -> Vec<String> {
+        let Self {
+            attached,
+            class_name,
+            fluid,
+            ordered,
+            size,
+            stackable,
+            unstackable,
+            vertical,
+            widths,
+            ..
+        } = self;
+
+        cx!(
+            use_str("ui"),
+            use_option(size),
+            use_key(*fluid, "fluid"),
+            use_key(*ordered, "ordered"),
+            use_key(*unstackable, "unstackable"),
+            use_key(*vertical, "vertical"),
+            use_key_or_option_and_key(attached, "attached"),
+            use_option_and_key(stackable, "stackable"),
+            use_option(widths),
+            use_str("steps"),
+            use_option(class_name)
+        )
+    }
+}
+
+impl From<StepGroupAttachedPosition> for &'static str {
+    fn from(s: StepGroupAttachedPosition) -> Self {
+        use StepGroupAttachedPosition::*;
+
+        match s {
+            Top => "top",
+            Bottom => "bottom",
+        }
+    }
+}
+
+impl AsRef<str> for StepGroupAttachedPosition {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<StepGroupStackable> for &'static str {
+    fn from(s: StepGroupStackable) -> Self {
+        use StepGroupStackable::*;
+
+        match s {
+            Tablet => "tablet",
+            Computer => "computer",
+        }
+    }
+}
+
+impl AsRef<str> for StepGroupStackable {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
@@ -43,6 +43,12 @@ pub fn use_key_or_option_and_key<V: AsRef<str>, K: AsRef<str>>(val: &Either<bool
     }
 }
 
+pub fn use_multiple_prop<V: AsRef<str>, K: AsRef<str>>(vals: &[V], key: K) -> Vec<String> {
+    vals.iter()
+        .flat_map(|val| vec![val.as_ref().to_owned(), key.as_ref().to_owned()])
+        .collect()
+}
+
 pub fn use_text_align<V: AsRef<str>>(val: V) -> Vec<String> {
     if val.as_ref() == "justified" {
         vec!["justified".to_string()]
@@ -51,6 +57,13 @@ pub fn use_text_align<V: AsRef<str>>(val: V) -> Vec<String> {
     }
 }
 
+pub fn use_option_text_align<V: AsRef<str>>(val: &Option<V>) -> Vec<String> {
+    match val {
+        Some(v) => use_text_align(v),
+        None => vec![],
+    }
+}
+
 #[macro_export]
 macro_rules! cx {
     () => { vec![] };
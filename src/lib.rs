@@ -1,3 +1,4 @@
 pub mod collections;
+pub mod elements;
 pub mod sui;
 mod helper;
\ No newline at end of file
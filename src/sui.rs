@@ -27,6 +27,26 @@ pub enum Sizes {
     Massive,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Widths {
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Eleven,
+    Twelve,
+    Thirteen,
+    Fourteen,
+    Fifteen,
+    Sixteen,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Flip {
     Horizontally,
@@ -39,6 +59,21 @@ pub enum Float {
     Right
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TextAlign {
+    Left,
+    Center,
+    Right,
+    Justified,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VerticalAlign {
+    Top,
+    Middle,
+    Bottom,
+}
+
 impl From<Colors> for &'static str {
     fn from(c: Colors) -> Self {
         use Colors::*;
@@ -89,6 +124,37 @@ impl AsRef<str> for Sizes {
     }
 }
 
+impl From<Widths> for &'static str {
+    fn from(w: Widths) -> Self {
+        use Widths::*;
+
+        match w {
+            One => "one",
+            Two => "two",
+            Three => "three",
+            Four => "four",
+            Five => "five",
+            Six => "six",
+            Seven => "seven",
+            Eight => "eight",
+            Nine => "nine",
+            Ten => "ten",
+            Eleven => "eleven",
+            Twelve => "twelve",
+            Thirteen => "thirteen",
+            Fourteen => "fourteen",
+            Fifteen => "fifteen",
+            Sixteen => "sixteen",
+        }
+    }
+}
+
+impl AsRef<str> for Widths {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
 impl From<Flip> for &'static str {
     fn from(f: Flip) -> Self {
         use Flip::*;
@@ -121,4 +187,41 @@ impl AsRef<str> for Float {
     fn as_ref(&self) -> &str {
         (*self).into()
     }
+}
+
+impl From<TextAlign> for &'static str {
+    fn from(t: TextAlign) -> Self {
+        use TextAlign::*;
+
+        match t {
+            Left => "left",
+            Center => "center",
+            Right => "right",
+            Justified => "justified",
+        }
+    }
+}
+
+impl AsRef<str> for TextAlign {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl From<VerticalAlign> for &'static str {
+    fn from(v: VerticalAlign) -> Self {
+        use VerticalAlign::*;
+
+        match v {
+            Top => "top",
+            Middle => "middle",
+            Bottom => "bottom",
+        }
+    }
+}
+
+impl AsRef<str> for VerticalAlign {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
 }
\ No newline at end of file